@@ -0,0 +1,51 @@
+//! Knuth 算法 D 与逐位移位减法的对比
+//!
+//! cargo bench --bench div
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algebra::Uint;
+use rand::thread_rng;
+
+const ITERATIONS: u32 = 200;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<const LIMBS: usize>() {
+    let mut rng = thread_rng();
+    let a = Uint::<LIMBS>::rand(&mut rng);
+    // 除数取一半位数，对应 mul_mod 等场景中的 Wide / Uint
+    let b = Uint::<LIMBS>::rand(&mut rng).wrapping_shr(Uint::<LIMBS>::BITS as u32 / 2);
+
+    let knuth = time(|| {
+        black_box(black_box(a).div_rem(black_box(&b)));
+    });
+    let binary = time(|| {
+        black_box(black_box(a).div_rem_binary(black_box(&b)));
+    });
+
+    println!(
+        "U{:<5} knuth: {:>12?} binary: {:>12?} speedup: {:.1}x",
+        Uint::<LIMBS>::BITS,
+        knuth,
+        binary,
+        binary.as_secs_f64() / knuth.as_secs_f64()
+    );
+}
+
+fn main() {
+    // U256 .. U8192
+    bench::<4>();
+    bench::<8>();
+    bench::<16>();
+    bench::<32>();
+    bench::<64>();
+    bench::<128>();
+}
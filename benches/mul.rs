@@ -0,0 +1,52 @@
+//! split_mul 与 square_wide 在不同宽度下的耗时
+//!
+//! cargo bench --bench mul
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algebra::Uint;
+use rand::thread_rng;
+
+const ITERATIONS: u32 = 2000;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<const LIMBS: usize>() {
+    let mut rng = thread_rng();
+    let a = Uint::<LIMBS>::rand(&mut rng);
+    let b = Uint::<LIMBS>::rand(&mut rng);
+
+    let mul = time(|| {
+        black_box(black_box(a).split_mul(black_box(&b)));
+    });
+    let square = time(|| {
+        black_box(black_box(a).square_wide());
+    });
+
+    println!(
+        "U{:<5} mul: {:>12?} square: {:>12?}",
+        Uint::<LIMBS>::BITS,
+        mul,
+        square
+    );
+}
+
+fn main() {
+    // U256 .. U8192
+    bench::<4>();
+    bench::<8>();
+    bench::<16>();
+    bench::<24>();
+    bench::<32>();
+    bench::<48>();
+    bench::<64>();
+    bench::<96>();
+    bench::<128>();
+}
@@ -0,0 +1,47 @@
+//! CRT 解密与直接计算 c^d mod n 的对比
+//!
+//! cargo bench --bench rsa
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algebra::rsa::gen_key;
+use algebra::Uint;
+use rand::thread_rng;
+
+const ITERATIONS: u32 = 20;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<const LIMBS: usize>() {
+    let mut rng = thread_rng();
+    let (private, public) = gen_key::<LIMBS>(&mut rng);
+    let c = Uint::<LIMBS>::rand_mod(&mut rng, &public.n);
+
+    let crt = time(|| {
+        black_box(private.decrypt(black_box(&c)));
+    });
+    let full = time(|| {
+        black_box(black_box(c).mod_exp(private.d(), private.n()));
+    });
+
+    println!(
+        "RSA-{:<5} crt: {:>12?} mod_exp: {:>12?} speedup: {:.1}x",
+        Uint::<LIMBS>::BITS,
+        crt,
+        full,
+        full.as_secs_f64() / crt.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench::<8>();
+    bench::<16>();
+    bench::<32>();
+}
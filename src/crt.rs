@@ -1,6 +1,8 @@
+use alloc::vec::Vec;
+
 use crate::inverse::modular_inverse;
 
-fn chinese_remainder_theorem(a: Vec<i64>, m: Vec<i64>) -> Option<i64> {
+pub fn chinese_remainder_theorem(a: Vec<i64>, m: Vec<i64>) -> Option<i64> {
     let prod: i64 = m.iter().product();
     let mut x = 0;
 
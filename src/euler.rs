@@ -1,4 +1,4 @@
-fn euler_phi(_n: u64) -> u64 {
+pub fn euler_phi(_n: u64) -> u64 {
     let mut n = _n;
     let mut result = n;
     let mut i = 2;
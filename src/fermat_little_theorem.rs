@@ -1,6 +1,6 @@
 use crate::exponent::fast_modular_exponentiation;
 
-fn modular_exponentiation_with_fermat(a: u64, b: u64, p: u64) -> u64 {
+pub fn modular_exponentiation_with_fermat(a: u64, b: u64, p: u64) -> u64 {
     fast_modular_exponentiation(a, b % (p - 1), p)
 }
 
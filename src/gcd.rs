@@ -34,7 +34,7 @@ pub fn gcd2(_x: u64, _y: u64) -> u64 {
     }
 }
 
-fn binary_gcd(mut u: u64, mut v: u64) -> u64 {
+pub fn binary_gcd(mut u: u64, mut v: u64) -> u64 {
     if u == 0 {
         return v;
     }
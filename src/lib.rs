@@ -0,0 +1,31 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod crt;
+#[cfg(feature = "alloc")]
+pub mod diophantine_equation;
+pub mod euler;
+pub mod exponent;
+pub mod ext_euc;
+pub mod fermat_little_theorem;
+pub mod gcd;
+pub mod inverse;
+#[cfg(feature = "alloc")]
+pub mod linear_congruence;
+pub mod montgomery;
+pub mod num;
+#[cfg(feature = "alloc")]
+pub mod prime;
+pub mod rsa;
+pub mod wilson;
+
+#[cfg(feature = "alloc")]
+pub use num::BigUint;
+pub use num::{
+    BarrettParams, Choice, ConstMontyForm, ConstMontyParams, Exponent, Int, Limb, ModInt, Modulus,
+    MontyForm, MontyParams, OverflowError, ParseError, Uint, Wide, U1024, U128, U2048, U256, U4096,
+    U512, U64, U8192,
+};
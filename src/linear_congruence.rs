@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 use crate::ext_euc::ext_gcd;
 use crate::inverse::modular_inverse;
 
-fn solve_linear_congruence(a: i64, b: i64, n: i64) -> Option<Vec<i64>> {
+pub fn solve_linear_congruence(a: i64, b: i64, n: i64) -> Option<Vec<i64>> {
     let (d, _x, _y) = ext_gcd(a, n);
     if b % d == 0 {
         let a_prime = a / d;
@@ -1,20 +1,11 @@
-#![allow(dead_code)]
-
-mod crt;
-mod diophantine_equation;
-mod euler;
-mod exponent;
-mod ext_euc;
-mod fermat_little_theorem;
-mod gcd;
-mod inverse;
-mod linear_congruence;
-mod montgomery;
-mod num;
-mod prime;
-mod rsa;
-mod wilson;
+use algebra::{MontyParams, U128};
 
 fn main() {
-    println!("Hello, world!");
+    let a = U128::from_u128(230679353788795331459744549142118481455);
+    let b = U128::from_u128(146263473042228956998536595460379662786);
+    let m = U128::from_u128(287215270712012985982119861826231487661);
+
+    let params = MontyParams::init(&m).expect("modulus must be odd");
+    let r = params.to_monty_form(&a).mul(&params.to_monty_form(&b));
+    println!("{} * {} mod {} = {}", a, b, m, r.normalize());
 }
@@ -1,4 +1,4 @@
-use std::ops::Add;
+use core::ops::Add;
 
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
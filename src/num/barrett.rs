@@ -0,0 +1,323 @@
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+use crate::num::mul::karatsuba::schoolbook_mul;
+use crate::num::pow_mod::{window_size, Exponent, SlidingWindows, MAX_WINDOW};
+use crate::num::uint::Uint;
+use crate::num::wide::Wide;
+
+/// Barrett 约减参数，适用于任意非零模数 (包括偶数)
+///
+/// 记 b = 2^64，k 为 n 的 limb 数，预计算 mu = floor(b^{2k} / n)。对 t < b^{2k}，
+/// 只用 t 的高 k + 1 个 limb 与 mu 相乘估计商 q，q 比真实商至多小 2，
+/// 余数 t - q * n < 3n 在模 b^{k+1} 下计算 (HAC 14.42)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BarrettParams<const LIMBS: usize> {
+    n: Uint<LIMBS>,
+    k: usize,
+    /// 至多 k + 1 个 limb
+    mu: Wide<LIMBS>,
+}
+
+impl<const LIMBS: usize> BarrettParams<LIMBS> {
+    /// Returns `None` if `n` is zero.
+    #[inline(always)]
+    pub fn init(n: &Uint<LIMBS>) -> Option<Self> {
+        if n.is_zero() {
+            return None;
+        }
+        let k = n.bits().div_ceil(Limb::BITS);
+
+        // n = 1 时 mu = b^2 可能无法表示，约减结果恒为 0，mu 不会被使用
+        let mu = if *n == Uint::ONE {
+            Wide::ZERO
+        } else if k == LIMBS {
+            // floor(2^{2k} / n) = floor((2^{2k} - 1) / n) + [ (2^{2k} - 1) mod n == n - 1 ]
+            let (mu, r) = Wide::MAX.div(n);
+            if r == *n - &Uint::ONE {
+                mu.add(&Wide::ONE)
+            } else {
+                mu
+            }
+        } else {
+            let mut limbs = [[Limb::ZERO; LIMBS]; 2];
+            limbs.as_flattened_mut()[2 * k] = Limb::ONE;
+            Wide::from((Uint::new(limbs[0]), Uint::new(limbs[1])))
+                .div(n)
+                .0
+        };
+
+        // n = b^{k-1} 时 mu = b^{k+1} 需要 k + 2 个 limb，截断为 b^{k+1} - 1，商的估计至多再小 1
+        let mut limbs = [mu.low.limbs, mu.high.limbs];
+        let flat = limbs.as_flattened_mut();
+        if k + 1 < flat.len() && flat[k + 1].is_nonzero() {
+            flat[k + 1] = Limb::ZERO;
+            flat[..k + 1].fill(Limb::MAX);
+        }
+        let mu = Wide::from((Uint::new(limbs[0]), Uint::new(limbs[1])));
+
+        Some(Self { n: *n, k, mu })
+    }
+
+    #[inline(always)]
+    pub fn modulus(&self) -> &Uint<LIMBS> {
+        &self.n
+    }
+
+    /// x mod n
+    ///
+    /// 先约减 x 最高的 2k 个 limb，之后每次将余数与下一段至多 k 个 limb 拼接后再约减，
+    /// 每段都小于 n * b^k <= b^{2k}。k = LIMBS 时只需一次
+    #[inline(always)]
+    pub fn reduce(&self, x: &Wide<LIMBS>) -> Uint<LIMBS> {
+        if self.n == Uint::ONE {
+            return Uint::ZERO;
+        }
+
+        let k = self.k;
+        let x = [x.low.limbs, x.high.limbs];
+        let x = x.as_flattened();
+        let mut pos = x.len() - 2 * k;
+
+        let mut t = [[Limb::ZERO; LIMBS]; 2];
+        let t = t.as_flattened_mut();
+        t[..2 * k].copy_from_slice(&x[pos..]);
+        let mut r = self.reduce_step(t);
+
+        while pos > 0 {
+            let c = pos.min(k);
+            pos -= c;
+            t.fill(Limb::ZERO);
+            t[..c].copy_from_slice(&x[pos..pos + c]);
+            t[c..c + k].copy_from_slice(&r.limbs[..k]);
+            r = self.reduce_step(t);
+        }
+
+        r
+    }
+
+    /// t mod n，要求 t < b^{2k}
+    #[inline(always)]
+    fn reduce_step(&self, t: &[Limb]) -> Uint<LIMBS> {
+        let k = self.k;
+        let mu = [self.mu.low.limbs, self.mu.high.limbs];
+        let mut n = [[Limb::ZERO; LIMBS]; 2];
+        n[0] = self.n.limbs;
+        let n = &n.as_flattened()[..k + 1];
+
+        // q = floor(floor(t / b^{k-1}) * mu / b^{k+1})
+        let mut q2 = [[Limb::ZERO; LIMBS]; 4];
+        let q2 = &mut q2.as_flattened_mut()[..2 * k + 2];
+        schoolbook_mul(&t[k - 1..2 * k], &mu.as_flattened()[..k + 1], q2);
+        let q = &q2[k + 1..];
+
+        // r = t - q * n < 4n < b^{k+1}，只需计算模 b^{k+1} 的部分
+        let mut qn = [[Limb::ZERO; LIMBS]; 2];
+        let qn = &mut qn.as_flattened_mut()[..k + 1];
+        mul_low(q, n, qn);
+
+        let mut r = [[Limb::ZERO; LIMBS]; 2];
+        let flat = r.as_flattened_mut();
+        let mut borrow = Limb::ZERO;
+        for i in 0..=k {
+            let (d, b) = t[i].sbb(qn[i], borrow);
+            flat[i] = d;
+            borrow = b;
+        }
+        for _ in 0..3 {
+            sub_if_ge(&mut flat[..k + 1], n);
+        }
+
+        Uint::new(r[0])
+    }
+
+    /// a * b mod n，a, b 可以是任意值
+    #[inline(always)]
+    pub fn mul_mod(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce(&a.split_mul(b))
+    }
+
+    #[inline(always)]
+    pub fn square_mod(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce(&a.square_wide())
+    }
+
+    /// base^{exp} mod n，从左到右滑动窗口
+    pub fn pow_mod<X: Exponent + ?Sized>(&self, base: &Uint<LIMBS>, exp: &X) -> Uint<LIMBS> {
+        let one = self.reduce(&Wide::ONE);
+        let bits = exp.bits();
+        if bits == 0 {
+            return one;
+        }
+
+        let window = window_size(bits);
+        let base = self.reduce(&Wide::from((*base, Uint::ZERO)));
+
+        // 奇数次幂表: base^1, base^3, ..., base^{2^w - 1}
+        let mut table = [Uint::ZERO; 1 << (MAX_WINDOW - 1)];
+        table[0] = base;
+        if window > 1 {
+            let base_sq = self.square_mod(&base);
+            for i in 1..(1 << (window - 1)) {
+                table[i] = self.mul_mod(&table[i - 1], &base_sq);
+            }
+        }
+
+        let mut result = one;
+        for (squarings, value) in SlidingWindows::new(exp, window) {
+            for _ in 0..squarings {
+                result = self.square_mod(&result);
+            }
+            if let Some(value) = value {
+                result = self.mul_mod(&result, &table[value >> 1]);
+            }
+        }
+
+        result
+    }
+}
+
+/// out = a * b mod b^{out.len()}，a、b 的长度不低于 out
+#[inline(always)]
+fn mul_low(a: &[Limb], b: &[Limb], out: &mut [Limb]) {
+    let len = out.len();
+    out.fill(Limb::ZERO);
+    for i in 0..len {
+        let mut carry = Limb::ZERO;
+        for j in 0..len - i {
+            let (w, c) = out[i + j].mac(a[i], b[j], carry);
+            out[i + j] = w;
+            carry = c;
+        }
+    }
+}
+
+/// r >= n 时 r -= n，r 与 n 长度相同，不依赖比较结果分支
+#[inline(always)]
+fn sub_if_ge(r: &mut [Limb], n: &[Limb]) {
+    let mut borrow = Limb::ZERO;
+    for (&r_i, &n_i) in r.iter().zip(n) {
+        borrow = r_i.sbb(n_i, borrow).1;
+    }
+
+    // 借位说明 r < n，保留 r
+    let keep = Choice::from_mask(borrow.0);
+    let mut borrow = Limb::ZERO;
+    for (r_i, &n_i) in r.iter_mut().zip(n) {
+        let (d, b) = r_i.sbb(n_i, borrow);
+        *r_i = Limb::ct_select(d, *r_i, keep);
+        borrow = b;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::barrett::BarrettParams;
+    use crate::num::uint::{U128, U256, U64};
+    use crate::num::wide::Wide;
+
+    #[test]
+    fn test_reduce() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let n = U128::rand(&mut rng).wrapping_shr(rng.gen_range(0..128));
+            if n.is_zero() {
+                continue;
+            }
+            let params = BarrettParams::init(&n).unwrap();
+            let x = Wide::from((U128::rand(&mut rng), U128::rand(&mut rng)));
+            assert_eq!(x.rem(&n), params.reduce(&x), "n: {}", n);
+        }
+    }
+
+    #[test]
+    fn test_reduce_edge() {
+        assert!(BarrettParams::init(&U128::ZERO).is_none());
+
+        let one = BarrettParams::init(&U128::ONE).unwrap();
+        assert_eq!(U128::ZERO, one.reduce(&Wide::MAX));
+
+        for n in [
+            U128::from_u64(2),
+            U128::from_u64(3),
+            U128::ONE.wrapping_shl(64),
+            U128::ONE.wrapping_shl(127),
+            U128::MAX,
+        ] {
+            let params = BarrettParams::init(&n).unwrap();
+            for x in [Wide::ZERO, Wide::ONE, Wide::MAX, Wide::from((n, n))] {
+                assert_eq!(x.rem(&n), params.reduce(&x), "n: {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduce_limb_boundaries() {
+        // n 恰为 b^{k-1} 时 mu 被截断，n 远窄于 U256 时分多段约减
+        let mut rng = thread_rng();
+        for j in [0, 1, 2, 3] {
+            let pow = U256::ONE.wrapping_shl(64 * j);
+            for n in [
+                pow,
+                pow.wrapping_add(&U256::ONE),
+                pow.wrapping_sub(&U256::ONE),
+            ] {
+                let Some(params) = BarrettParams::init(&n) else {
+                    continue;
+                };
+                for _ in 0..100 {
+                    let x = Wide::from((U256::rand(&mut rng), U256::rand(&mut rng)));
+                    assert_eq!(x.rem(&n), params.reduce(&x), "n: {}", n);
+                }
+                assert_eq!(Wide::MAX.rem(&n), params.reduce(&Wide::MAX), "n: {}", n);
+            }
+        }
+
+        let params = BarrettParams::init(&U64::MAX).unwrap();
+        assert_eq!(U64::ZERO, params.reduce(&Wide::MAX));
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let n = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..256));
+            if n.is_zero() {
+                continue;
+            }
+            let params = BarrettParams::init(&n).unwrap();
+            let a = U256::rand(&mut rng);
+            let b = U256::rand(&mut rng);
+            assert_eq!(a.mul_mod(&b, &n), params.mul_mod(&a, &b), "n: {}", n);
+            assert_eq!(a.mul_mod(&a, &n), params.square_mod(&a), "n: {}", n);
+        }
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            // 偶数模数
+            let n = U128::rand(&mut rng)
+                .wrapping_shl(1)
+                .bitor(&U128::from_u64(2));
+            let params = BarrettParams::init(&n).unwrap();
+            let a = U128::rand(&mut rng);
+            let e: u64 = rng.gen();
+
+            let mut expect = U128::ONE % n;
+            for i in (0..64).rev() {
+                expect = expect.mul_mod(&expect, &n);
+                if (e >> i) & 1 == 1 {
+                    expect = expect.mul_mod(&a, &n);
+                }
+            }
+            assert_eq!(expect, params.pow_mod(&a, &e), "a: {} e: {} n: {}", a, e, n);
+        }
+
+        let params = BarrettParams::init(&U64::from_u64(1 << 32)).unwrap();
+        assert_eq!(U64::ONE, params.pow_mod(&U64::from_u64(3), &0u32));
+        assert_eq!(U64::ZERO, params.pow_mod(&U64::from_u64(2), &32u32));
+    }
+}
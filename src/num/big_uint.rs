@@ -0,0 +1,665 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+use core::str::FromStr;
+
+use crate::num::div::div_rem_limbs;
+use crate::num::error::{OverflowError, ParseError};
+use crate::num::fmt::{fmt_decimal, fmt_pow2};
+use crate::num::limb::Limb;
+use crate::num::macros::forward_binop;
+use crate::num::mul::karatsuba::{
+    karatsuba_mul, karatsuba_square, schoolbook_mul, schoolbook_square, KARATSUBA_THRESHOLD,
+};
+use crate::num::pow_mod::Exponent;
+use crate::num::uint::Uint;
+
+/// 任意精度的无符号整数，limb 按小端存放于堆上
+///
+/// 始终保持规范形式: 最高 limb 非零，零表示为空数组
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<Limb>,
+}
+
+impl BigUint {
+    #[inline(always)]
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    #[inline(always)]
+    pub fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    #[inline(always)]
+    pub fn from_u64(n: u64) -> Self {
+        Self::from_limbs(&[Limb(n)])
+    }
+
+    #[inline(always)]
+    pub fn from_u128(n: u128) -> Self {
+        Self::from_limbs(&[Limb(n as u64), Limb((n >> Limb::BITS) as u64)])
+    }
+
+    /// 由小端 limb 构造，高位的零 limb 会被去掉
+    pub fn from_limbs(limbs: &[Limb]) -> Self {
+        let mut ret = Self {
+            limbs: limbs.to_vec(),
+        };
+        ret.normalize();
+        ret
+    }
+
+    /// 规范形式下的小端 limb，零为空切片
+    #[inline(always)]
+    pub fn as_limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    #[inline(always)]
+    pub fn from_uint<const LIMBS: usize>(n: &Uint<LIMBS>) -> Self {
+        Self::from_limbs(&n.limbs)
+    }
+
+    /// Returns `None` if `self` does not fit in `Uint<LIMBS>`.
+    pub fn to_uint<const LIMBS: usize>(&self) -> Option<Uint<LIMBS>> {
+        if self.limbs.len() > LIMBS {
+            return None;
+        }
+        let mut ret = Uint::ZERO;
+        ret.limbs[..self.limbs.len()].copy_from_slice(&self.limbs);
+        Some(ret)
+    }
+
+    #[inline(always)]
+    fn normalize(&mut self) {
+        while self.limbs.last().is_some_and(|l| l.is_zero()) {
+            self.limbs.pop();
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn is_one(&self) -> bool {
+        self.limbs.len() == 1 && self.limbs[0] == Limb::ONE
+    }
+
+    #[inline(always)]
+    pub fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// 有效位数，零为 0
+    #[inline(always)]
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * Limb::BITS - top.0.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// 第 index 位是否为 1，超出有效位时为 false
+    #[inline(always)]
+    pub fn bit(&self, index: usize) -> bool {
+        match self.limbs.get(index / Limb::BITS) {
+            Some(limb) => (limb.0 >> (index % Limb::BITS)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Returns `None` if `rhs > self`.
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if self.limbs.len() < rhs.limbs.len() {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = Limb::ZERO;
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let b = rhs.limbs.get(i).copied().unwrap_or(Limb::ZERO);
+            let (w, c) = a.sbb(b, borrow);
+            limbs.push(w);
+            borrow = c;
+        }
+        if borrow.is_nonzero() {
+            return None;
+        }
+        let mut ret = Self { limbs };
+        ret.normalize();
+        Some(ret)
+    }
+
+    pub fn square(&self) -> Self {
+        let n = self.limbs.len();
+        let mut out = vec![Limb::ZERO; 2 * n];
+        if n >= KARATSUBA_THRESHOLD {
+            let mut scratch = vec![Limb::ZERO; 8 * n];
+            karatsuba_square(&self.limbs, &mut out, &mut scratch);
+        } else {
+            schoolbook_square(&self.limbs, &mut out);
+        }
+        let mut ret = Self { limbs: out };
+        ret.normalize();
+        ret
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut ret = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                ret = &ret * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.square();
+            }
+        }
+        ret
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        if self < rhs {
+            return (Self::zero(), self.clone());
+        }
+
+        let (m, n) = (self.limbs.len(), rhs.limbs.len());
+        let mut q = vec![Limb::ZERO; m];
+        let mut r = vec![Limb::ZERO; n];
+        let mut buf = vec![Limb::ZERO; m + n + 1];
+        div_rem_limbs(&self.limbs, &rhs.limbs, &mut q, &mut r, &mut buf);
+
+        let (mut q, mut r) = (Self { limbs: q }, Self { limbs: r });
+        q.normalize();
+        r.normalize();
+        (q, r)
+    }
+
+    /// (self + rhs) mod m，要求 self、rhs 均小于 m
+    #[inline(always)]
+    pub fn add_mod(&self, rhs: &Self, m: &Self) -> Self {
+        let sum = self + rhs;
+        if &sum >= m {
+            sum - m
+        } else {
+            sum
+        }
+    }
+
+    /// (self - rhs) mod m，要求 self、rhs 均小于 m
+    #[inline(always)]
+    pub fn sub_mod(&self, rhs: &Self, m: &Self) -> Self {
+        if self >= rhs {
+            self - rhs
+        } else {
+            &(self + m) - rhs
+        }
+    }
+
+    #[inline(always)]
+    pub fn mul_mod(&self, rhs: &Self, m: &Self) -> Self {
+        &(self * rhs) % m
+    }
+
+    /// 从高位到低位的平方-乘算法
+    ///
+    /// Panics if `m` is zero.
+    pub fn pow_mod<X: Exponent + ?Sized>(&self, exp: &X, m: &Self) -> Self {
+        let base = self % m;
+        let mut ret = &Self::one() % m;
+        for i in (0..exp.bits()).rev() {
+            ret = &ret.square() % m;
+            if exp.bit(i) {
+                ret = ret.mul_mod(&base, m);
+            }
+        }
+        ret
+    }
+
+    /// 扩展欧几里得算法，系数始终保持在 [0, m) 内
+    ///
+    /// Returns `None` if `self` is not invertible modulo `m`.
+    pub fn mod_inv(&self, m: &Self) -> Option<Self> {
+        assert!(!m.is_zero(), "attempt to calculate with zero modulus!");
+        let (mut r0, mut r1) = (m.clone(), self % m);
+        let (mut t0, mut t1) = (Self::zero(), &Self::one() % m);
+
+        while !r1.is_zero() {
+            let (q, r2) = r0.div_rem(&r1);
+            let t2 = t0.sub_mod(&t1.mul_mod(&q, m), m);
+            (r0, r1) = (r1, r2);
+            (t0, t1) = (t1, t2);
+        }
+
+        if r0.is_one() {
+            Some(t0)
+        } else {
+            None
+        }
+    }
+
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            (a, b) = (b, r);
+        }
+        a
+    }
+
+    /// 按给定进制 (2 到 36) 解析，允许一个前导 `+`
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]`"
+        );
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut limbs: Vec<Limb> = Vec::new();
+        for c in digits.chars() {
+            let d = c.to_digit(radix).ok_or(ParseError::InvalidDigit)?;
+            let mut carry = Limb(d as u64);
+            for limb in limbs.iter_mut() {
+                let (w, c) = Limb::ZERO.mac(*limb, Limb(radix as u64), carry);
+                *limb = w;
+                carry = c;
+            }
+            if carry.is_nonzero() {
+                limbs.push(carry);
+            }
+        }
+        Ok(Self { limbs })
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BigUint {
+    fn from(n: Uint<LIMBS>) -> Self {
+        Self::from_uint(&n)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigUint> for Uint<LIMBS> {
+    type Error = OverflowError;
+
+    fn try_from(n: &BigUint) -> Result<Self, Self::Error> {
+        n.to_uint().ok_or(OverflowError)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        Self::from_u64(n)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> Self {
+        Self::from_u128(n)
+    }
+}
+
+impl Exponent for BigUint {
+    #[inline(always)]
+    fn bits(&self) -> usize {
+        BigUint::bits(self)
+    }
+
+    #[inline(always)]
+    fn bit(&self, index: usize) -> bool {
+        BigUint::bit(self, index)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 规范形式下 limb 数多者更大
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| {
+            self.limbs
+                .iter()
+                .rev()
+                .map(|l| l.0)
+                .cmp(other.limbs.iter().rev().map(|l| l.0))
+        })
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = Limb::ZERO;
+        for (i, &a) in long.limbs.iter().enumerate() {
+            let b = short.limbs.get(i).copied().unwrap_or(Limb::ZERO);
+            let (w, c) = a.adc(b, carry);
+            limbs.push(w);
+            carry = c;
+        }
+        if carry.is_nonzero() {
+            limbs.push(carry);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempted to subtract with overflow")
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+
+        let (a, b) = (&self.limbs, &rhs.limbs);
+        let mut out;
+        if a.len().min(b.len()) >= KARATSUBA_THRESHOLD {
+            // Karatsuba 要求等长，短的一方补零
+            let n = a.len().max(b.len());
+            let (mut x, mut y) = (a.clone(), b.clone());
+            x.resize(n, Limb::ZERO);
+            y.resize(n, Limb::ZERO);
+            out = vec![Limb::ZERO; 2 * n];
+            let mut scratch = vec![Limb::ZERO; 8 * n];
+            karatsuba_mul(&x, &y, &mut out, &mut scratch);
+        } else {
+            out = vec![Limb::ZERO; a.len() + b.len()];
+            schoolbook_mul(a, b, &mut out);
+        }
+
+        let mut ret = BigUint { limbs: out };
+        ret.normalize();
+        ret
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_binop!([] BigUint; Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> Self::Output {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let (limb_shift, bit_shift) = (shift / Limb::BITS, shift % Limb::BITS);
+        let mut limbs = vec![Limb::ZERO; limb_shift];
+        let mut carry = Limb::ZERO;
+        for &l in self.limbs.iter() {
+            if bit_shift == 0 {
+                limbs.push(l);
+            } else {
+                limbs.push(Limb((l.0 << bit_shift) | carry.0));
+                carry = Limb(l.0 >> (Limb::BITS - bit_shift));
+            }
+        }
+        limbs.push(carry);
+        let mut ret = BigUint { limbs };
+        ret.normalize();
+        ret
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> Self::Output {
+        let (limb_shift, bit_shift) = (shift / Limb::BITS, shift % Limb::BITS);
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let src = &self.limbs[limb_shift..];
+        let mut limbs = Vec::with_capacity(src.len());
+        for i in 0..src.len() {
+            if bit_shift == 0 {
+                limbs.push(src[i]);
+            } else {
+                let high = src
+                    .get(i + 1)
+                    .map_or(0, |l| l.0 << (Limb::BITS - bit_shift));
+                limbs.push(Limb((src[i].0 >> bit_shift) | high));
+            }
+        }
+        let mut ret = BigUint { limbs };
+        ret.normalize();
+        ret
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = Self;
+
+    fn shl(self, shift: usize) -> Self::Output {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = Self;
+
+    fn shr(self, shift: usize) -> Self::Output {
+        &self >> shift
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 每个 limb 的十进制表示不超过 20 位
+        let mut limbs = self.limbs.clone();
+        let mut buf = vec![0u8; 20 * limbs.len().max(1)];
+        fmt_decimal(true, &mut limbs, &mut buf, f)
+    }
+}
+
+macro_rules! impl_fmt_pow2 {
+    ($($trait:path, $log2:expr, $upper:expr, $prefix:expr);+) => {
+        $(
+            impl $trait for BigUint {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let limbs: &[Limb] = if self.is_zero() {
+                        &[Limb::ZERO]
+                    } else {
+                        &self.limbs
+                    };
+                    let mut buf = vec![0u8; limbs.len() * Limb::BITS];
+                    fmt_pow2(limbs, $log2, $upper, $prefix, &mut buf, f)
+                }
+            }
+        )+
+    };
+}
+
+impl_fmt_pow2!(
+    fmt::LowerHex, 4, false, "0x";
+    fmt::UpperHex, 4, true, "0x";
+    fmt::Octal, 3, false, "0o";
+    fmt::Binary, 1, false, "0b"
+);
+
+impl FromStr for BigUint {
+    type Err = ParseError;
+
+    /// 十进制
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::big_uint::BigUint;
+    use crate::num::uint::{Uint, U128, U256, U4096};
+
+    #[test]
+    fn test_arith_u128() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let b: u64 = rng.gen();
+            let (x, y) = (BigUint::from_u64(a), BigUint::from_u64(b));
+            let (a, b) = (a as u128, b as u128);
+            assert_eq!(BigUint::from_u128(a + b), &x + &y);
+            assert_eq!(BigUint::from_u128(a * b), &x * &y);
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            if let (Some(q), Some(r)) = (a.checked_div(b), a.checked_rem(b)) {
+                assert_eq!(BigUint::from_u128(q), &x / &y);
+                assert_eq!(BigUint::from_u128(r), &x % &y);
+            }
+            if a >= b {
+                assert_eq!(BigUint::from_u128(a - b), &x - &y);
+            } else {
+                assert_eq!(None, x.checked_sub(&y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_uint() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // 超过 Karatsuba 阈值
+            let a = U4096::rand(&mut rng);
+            let b = U4096::rand(&mut rng);
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            let w = a.split_mul(&b);
+            let mut expect = w.low.as_limbs().to_vec();
+            expect.extend_from_slice(w.high.as_limbs());
+            assert_eq!(BigUint::from_limbs(&expect), &x * &y);
+            assert_eq!(&x * &x, x.square());
+
+            let (q, r) = a.div_rem(&b.wrapping_shr(1000));
+            let (bq, br) = x.div_rem(&(&y >> 1000));
+            assert_eq!(Some(q), bq.to_uint());
+            assert_eq!(Some(r), br.to_uint());
+        }
+    }
+
+    #[test]
+    fn test_uint_conversion() {
+        let mut rng = thread_rng();
+        let a = U256::rand(&mut rng);
+        let x = BigUint::from(a);
+        assert_eq!(Some(a), x.to_uint::<4>());
+        assert_eq!(Ok(a), U256::try_from(&x));
+        assert!(U128::try_from(&(&BigUint::one() << 128)).is_err());
+        assert_eq!(
+            Some(U128::MAX),
+            (&(&BigUint::one() << 128) - &BigUint::one()).to_uint()
+        );
+        assert_eq!(Some(Uint::<3>::ZERO), BigUint::zero().to_uint());
+        assert!(BigUint::from(U256::ZERO).is_zero());
+    }
+
+    #[test]
+    fn test_shift() {
+        let x = BigUint::from_u128(0xdead_beef_0123_4567_89ab_cdef);
+        for s in [0, 1, 63, 64, 65, 130, 200] {
+            assert_eq!(x, &(&x << s) >> s);
+        }
+        assert_eq!(BigUint::from_u128(1 << 100), &BigUint::one() << 100);
+        assert!((&x >> 1000).is_zero());
+    }
+
+    #[test]
+    fn test_factorial() {
+        let mut f = BigUint::one();
+        for i in 1..=30 {
+            f = f * BigUint::from_u64(i);
+        }
+        assert_eq!("265252859812191058636308480000000", f.to_string());
+        assert_eq!(Ok(f.clone()), f.to_string().parse());
+        assert_eq!(
+            BigUint::from_u64(3).pow(100).to_string(),
+            "515377520732011331036461129765621272702107522001"
+        );
+    }
+
+    #[test]
+    fn test_modular() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let m = U128::rand(&mut rng).bitor(&U128::ONE);
+            let a = U128::rand(&mut rng) % m;
+            let b = U128::rand(&mut rng) % m;
+            let e = U128::rand(&mut rng);
+            let (bm, ba, bb) = (BigUint::from(m), BigUint::from(a), BigUint::from(b));
+            assert_eq!(Some(a.add_mod(&b, &m)), ba.add_mod(&bb, &bm).to_uint());
+            assert_eq!(Some(a.sub_mod(&b, &m)), ba.sub_mod(&bb, &bm).to_uint());
+            assert_eq!(Some(a.mul_mod(&b, &m)), ba.mul_mod(&bb, &bm).to_uint());
+            assert_eq!(Some(a.mod_exp(&e, &m)), ba.pow_mod(&e, &bm).to_uint());
+            assert_eq!(
+                Some(a.mod_exp(&e, &m)),
+                ba.pow_mod(&BigUint::from(e), &bm).to_uint()
+            );
+            match a.mod_inv(&m) {
+                Some(inv) => assert_eq!(Some(inv), ba.mod_inv(&bm).and_then(|x| x.to_uint())),
+                None => assert_eq!(None, ba.mod_inv(&bm)),
+            }
+        }
+        let g = BigUint::from_u64(12).gcd(&BigUint::from_u64(18));
+        assert_eq!(BigUint::from_u64(6), g);
+    }
+
+    #[test]
+    fn test_fmt() {
+        let a: u128 = thread_rng().gen();
+        let x = BigUint::from_u128(a);
+        assert_eq!(format!("{:#x}", a), format!("{:#x}", x));
+        assert_eq!(format!("{:o}", a), format!("{:o}", x));
+        assert_eq!(format!("{:>40b}", a), format!("{:>40b}", x));
+        assert_eq!("0", BigUint::zero().to_string());
+        assert_eq!("0", format!("{:x}", BigUint::zero()));
+        assert_eq!(Ok(x), BigUint::from_str_radix(&format!("{:x}", a), 16));
+    }
+}
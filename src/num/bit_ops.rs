@@ -1,6 +1,6 @@
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
-use std::ops::{BitAnd, BitOr, Shl, Shr};
+use core::ops::{BitAnd, BitOr, Shl, Shr};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
@@ -40,7 +40,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     #[inline(always)]
-    pub(crate) fn bitor(&self, rhs: &Self) -> Self {
+    /// 第 index 位是否为 1
+    pub fn bit(&self, index: usize) -> bool {
+        let limb = self.limbs[index / Limb::BITS];
+        (limb.0 >> (index % Limb::BITS)) & 1 == 1
+    }
+
+    #[inline(always)]
+    pub fn bitor(&self, rhs: &Self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
         let mut i = 0;
 
@@ -53,7 +60,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     #[inline(always)]
-    pub(crate) fn bitand(&self, rhs: &Self) -> Self {
+    pub fn bitand(&self, rhs: &Self) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
         let mut i = 0;
 
@@ -66,12 +73,15 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     #[inline(always)]
-    pub(crate) fn wrapping_shl(&self, rhs: u32) -> Self {
+    pub fn wrapping_shl(&self, rhs: u32) -> Self {
         let shift_bit = rhs as usize;
 
         if shift_bit == 0 {
             return *self;
         }
+        if shift_bit >= Self::BITS {
+            return Self::ZERO;
+        }
 
         let mut limbs = [Limb::ZERO; LIMBS];
 
@@ -83,25 +93,30 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         for i in 0..(LIMBS - shift_num) {
             let high = self.limbs[i].wrapping_shl(shl_shift as u32);
             limbs[i + shift_num] = high.bitor(low);
-            low = self.limbs[i].wrapping_shr(shr_shift as u32);
+            low = self.limbs[i]
+                .checked_shr(shr_shift as u32)
+                .unwrap_or(Limb::ZERO);
         }
 
         Self { limbs }
     }
 
     #[inline(always)]
-    pub(crate) fn overflowing_shl(&self, rhs: u32) -> (Self, bool) {
+    pub fn overflowing_shl(&self, rhs: u32) -> (Self, bool) {
         let r = self.wrapping_shl(rhs);
         (r, r.wrapping_shr(rhs) != *self)
     }
 
     #[inline(always)]
-    pub(crate) fn wrapping_shr(&self, rhs: u32) -> Self {
+    pub fn wrapping_shr(&self, rhs: u32) -> Self {
         let shift_bit = rhs as usize;
 
         if shift_bit == 0 {
             return *self;
         }
+        if shift_bit >= Self::BITS {
+            return Self::ZERO;
+        }
 
         let mut limbs = [Limb::ZERO; LIMBS];
 
@@ -113,25 +128,25 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         for i in (shift_num..LIMBS).rev() {
             let low = self.limbs[i].wrapping_shr(shr_shift);
             limbs[i - shift_num] = high.bitor(low);
-            high = self.limbs[i].wrapping_shl(shl_shift);
+            high = self.limbs[i].checked_shl(shl_shift).unwrap_or(Limb::ZERO);
         }
 
         Self { limbs }
     }
 
     #[inline(always)]
-    pub(crate) fn overflowing_shr(&self, rhs: u32) -> (Self, bool) {
+    pub fn overflowing_shr(&self, rhs: u32) -> (Self, bool) {
         let r = self.wrapping_shr(rhs);
         (r, *self != r.wrapping_shl(rhs))
     }
 
     #[inline(always)]
-    pub(crate) fn wrapping_shr1(&self) -> Self {
+    pub fn wrapping_shr1(&self) -> Self {
         self.wrapping_shr(1)
     }
 
     #[inline(always)]
-    pub(crate) fn wrapping_shl1(&self) -> Self {
+    pub fn wrapping_shl1(&self) -> Self {
         self.wrapping_shl(1)
     }
 }
@@ -151,9 +166,10 @@ impl<const LIMBS: usize> Shl<&Uint<LIMBS>> for Uint<LIMBS> {
 
 #[cfg(test)]
 mod test {
-    use crate::num::uint::U128;
+    use crate::num::uint::{U128, U256};
     use rand::{thread_rng, Rng};
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_shr() {
         let mut rng = thread_rng();
@@ -176,6 +192,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_shl() {
         let mut rng = thread_rng();
@@ -199,6 +216,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_shift_by_limb_multiple() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a = U256::rand(&mut rng);
+            let shift = 64 * rng.gen_range(0..=4u32);
+            let shl = a.wrapping_shl(shift);
+            let shr = a.wrapping_shr(shift);
+            for i in 0..4 {
+                let j = i + (shift / 64) as usize;
+                if j < 4 {
+                    assert_eq!(a.limbs[i], shl.limbs[j]);
+                    assert_eq!(a.limbs[j], shr.limbs[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bit() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let i = rng.gen_range(0..128);
+            assert_eq!((a >> i) & 1 == 1, U128::from_u128(a).bit(i));
+        }
+    }
+
     #[test]
     fn test_trailing_zeros() {
         let mut rng = thread_rng();
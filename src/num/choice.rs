@@ -0,0 +1,79 @@
+use core::hint::black_box;
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// 恒定时间的布尔值，内部为全 0 (假) 或全 1 (真) 的掩码
+///
+/// 所有组合运算均为按位运算，不会引入分支
+#[derive(Copy, Clone, Debug)]
+pub struct Choice(u64);
+
+impl Choice {
+    pub const FALSE: Self = Self(0);
+    pub const TRUE: Self = Self(u64::MAX);
+
+    /// 由 0 或 1 构造
+    #[inline(always)]
+    pub fn from_bit(bit: u64) -> Self {
+        debug_assert!(bit <= 1);
+        // 阻止编译器将掩码运算还原为分支
+        Self(black_box(bit).wrapping_neg())
+    }
+
+    /// 由全 0 或全 1 的掩码构造，例如 `Limb::sbb` 返回的借位
+    #[inline(always)]
+    pub fn from_mask(mask: u64) -> Self {
+        debug_assert!(mask == 0 || mask == u64::MAX);
+        Self(black_box(mask))
+    }
+
+    #[inline(always)]
+    pub fn mask(self) -> u64 {
+        self.0
+    }
+
+    /// 0 或 1
+    #[inline(always)]
+    pub fn to_bit(self) -> u64 {
+        self.0 & 1
+    }
+
+    /// 转换为 `bool`，调用方可能据此分支，因此结果不再是恒定时间的
+    #[inline(always)]
+    pub fn to_bool_vartime(self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(b: bool) -> Self {
+        Self::from_bit(b as u64)
+    }
+}
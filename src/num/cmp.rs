@@ -1,25 +1,19 @@
 use crate::num::uint::Uint;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 // Impl Cmp
+// 比较总是遍历全部 limb，运行时间与首个不同 limb 的位置无关
 impl<const LIMBS: usize> PartialEq for Uint<LIMBS> {
     fn eq(&self, other: &Self) -> bool {
-        self.limbs.eq(&other.limbs)
+        self.ct_eq(other).to_bool_vartime()
     }
 }
 
 impl<const LIMBS: usize> Ord for Uint<LIMBS> {
     fn cmp(&self, other: &Self) -> Ordering {
-        for i in (0..LIMBS).rev() {
-            match self.limbs[i].0.cmp(&other.limbs[i].0) {
-                Ordering::Less => {
-                    return Ordering::Less;
-                }
-                Ordering::Greater => return Ordering::Greater,
-                Ordering::Equal => {}
-            }
-        }
-        Ordering::Equal
+        let lt = self.ct_lt(other).to_bit() as i8;
+        let gt = self.ct_gt(other).to_bit() as i8;
+        (gt - lt).cmp(&0)
     }
 }
 
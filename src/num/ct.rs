@@ -0,0 +1,127 @@
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// choice 为真时返回 b，否则返回 a
+    #[inline(always)]
+    pub fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut ret = Self::ZERO;
+        for i in 0..LIMBS {
+            ret.limbs[i] = Limb::ct_select(a.limbs[i], b.limbs[i], choice);
+        }
+        ret
+    }
+
+    /// choice 为真时交换 a, b
+    #[inline(always)]
+    pub fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for i in 0..LIMBS {
+            Limb::ct_swap(&mut a.limbs[i], &mut b.limbs[i], choice);
+        }
+    }
+
+    #[inline(always)]
+    pub fn ct_is_zero(&self) -> Choice {
+        let mut acc = 0u64;
+        for limb in self.limbs {
+            acc |= limb.0;
+        }
+        Limb(acc).ct_is_zero()
+    }
+
+    #[inline(always)]
+    pub fn ct_eq(&self, rhs: &Self) -> Choice {
+        let mut acc = 0u64;
+        for i in 0..LIMBS {
+            acc |= self.limbs[i].0 ^ rhs.limbs[i].0;
+        }
+        Limb(acc).ct_is_zero()
+    }
+
+    /// self < rhs 当且仅当 self - rhs 产生借位
+    #[inline(always)]
+    pub fn ct_lt(&self, rhs: &Self) -> Choice {
+        let (_, borrow) = self.sbb(rhs, Limb::ZERO);
+        Choice::from_mask(borrow.0)
+    }
+
+    #[inline(always)]
+    pub fn ct_gt(&self, rhs: &Self) -> Choice {
+        rhs.ct_lt(self)
+    }
+
+    /// self >= m 时返回 self - m，否则返回 self
+    #[inline(always)]
+    pub fn ct_sub_if_ge(&self, m: &Self) -> Self {
+        self.ct_sub_if_ge_with_carry(Limb::ZERO, m)
+    }
+
+    /// 对 carry * 2^BITS + self 做一次条件减法，要求其小于 2m
+    #[inline(always)]
+    pub(crate) fn ct_sub_if_ge_with_carry(&self, carry: Limb, m: &Self) -> Self {
+        let (diff, borrow) = self.sbb(m, Limb::ZERO);
+        // 高位进位抵消借位时结果不小于 m
+        let (_, borrow) = carry.sbb(Limb::ZERO, borrow);
+        Self::ct_select(&diff, self, Choice::from_mask(borrow.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::choice::Choice;
+    use crate::num::limb::Limb;
+    use crate::num::uint::U128;
+
+    #[test]
+    fn test_ct_cmp() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            // 覆盖高位相同、仅低位不同的情况
+            let b: u128 = match rng.gen_range(0..3) {
+                0 => rng.gen(),
+                1 => a ^ rng.gen::<u64>() as u128,
+                _ => a,
+            };
+            let (ua, ub) = (U128::from_u128(a), U128::from_u128(b));
+            assert_eq!(a == b, ua.ct_eq(&ub).to_bool_vartime());
+            assert_eq!(a < b, ua.ct_lt(&ub).to_bool_vartime());
+            assert_eq!(a > b, ua.ct_gt(&ub).to_bool_vartime());
+            assert_eq!(a == 0, ua.ct_is_zero().to_bool_vartime());
+        }
+        assert!(U128::ZERO.ct_is_zero().to_bool_vartime());
+    }
+
+    #[test]
+    fn test_ct_select_swap() {
+        let a = U128::from_u128(u128::MAX - 7);
+        let b = U128::from_u64(42);
+        assert_eq!(a, U128::ct_select(&a, &b, Choice::FALSE));
+        assert_eq!(b, U128::ct_select(&a, &b, Choice::TRUE));
+
+        let (mut x, mut y) = (a, b);
+        U128::ct_swap(&mut x, &mut y, Choice::from(false));
+        assert_eq!((a, b), (x, y));
+        U128::ct_swap(&mut x, &mut y, Choice::from(true));
+        assert_eq!((b, a), (x, y));
+    }
+
+    #[test]
+    fn test_ct_sub_if_ge() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let m: u128 = rng.gen_range(1..=u128::MAX);
+            let a: u128 = rng.gen();
+            let expect = if a >= m { a - m } else { a };
+            let actual = U128::from_u128(a).ct_sub_if_ge(&U128::from_u128(m));
+            assert_eq!(U128::from_u128(expect), actual, "a: {} m: {}", a, m);
+        }
+
+        // 2^128 + 1 - (2^128 - 1) = 2
+        let a = U128::ONE.ct_sub_if_ge_with_carry(Limb::ONE, &U128::MAX);
+        assert_eq!(U128::from_u64(2), a);
+    }
+}
@@ -1,11 +1,173 @@
-use std::ops::Div;
+use core::ops::Div;
 
+use crate::num::choice::Choice;
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
 
+/// Knuth 算法 D (TAOCP 4.3.1) 的核心循环
+///
+/// u 的长度为 m + n + 1，v 的长度为 n 且已规范化 (最高 limb 的最高位为 1)，
+/// 要求 u 的最高 n 个 limb 组成的数小于 v。
+/// 商写入 q[0..=m]，余数留在 u[0..n]，u 的其余 limb 清零
+pub(crate) fn div_rem_normalized(u: &mut [Limb], v: &[Limb], q: &mut [Limb]) {
+    let n = v.len();
+    let m = u.len() - n - 1;
+    let v_top = v[n - 1];
+    debug_assert!(v_top.0 >> (Limb::BITS - 1) == 1);
+
+    for j in (0..=m).rev() {
+        // 用 u 的最高两个 limb 除以 v 的最高 limb 估计商，估计值至多大 2
+        let (mut q_hat, mut r_hat, mut r_hat_overflow) = if u[j + n] == v_top {
+            let (r, c) = u[j + n - 1].adc(v_top, Limb::ZERO);
+            (Limb::MAX, r, c.is_nonzero())
+        } else {
+            let (q, r) = Limb::div_wide(u[j + n], u[j + n - 1], v_top);
+            (q, r, false)
+        };
+
+        // 借助 v 的次高 limb 修正，修正后估计值至多大 1
+        if n >= 2 {
+            while !r_hat_overflow {
+                let lhs = q_hat.0 as u128 * v[n - 2].0 as u128;
+                let rhs = ((r_hat.0 as u128) << Limb::BITS) | u[j + n - 2].0 as u128;
+                if lhs <= rhs {
+                    break;
+                }
+                q_hat = Limb(q_hat.0 - 1);
+                let (r, c) = r_hat.adc(v_top, Limb::ZERO);
+                r_hat = r;
+                r_hat_overflow = c.is_nonzero();
+            }
+        }
+
+        // u[j..=j+n] -= q_hat * v
+        let mut mul_carry = Limb::ZERO;
+        let mut borrow = Limb::ZERO;
+        for i in 0..n {
+            let (p, c) = Limb::ZERO.mac(q_hat, v[i], mul_carry);
+            mul_carry = c;
+            let (d, b) = u[i + j].sbb(p, borrow);
+            u[i + j] = d;
+            borrow = b;
+        }
+        let (d, b) = u[j + n].sbb(mul_carry, borrow);
+        u[j + n] = d;
+
+        // 估计值大 1 时结果为负，加回一个 v
+        if b.is_nonzero() {
+            q_hat = Limb(q_hat.0 - 1);
+            let mut carry = Limb::ZERO;
+            for i in 0..n {
+                let (s, c) = u[i + j].adc(v[i], carry);
+                u[i + j] = s;
+                carry = c;
+            }
+            u[j + n] = u[j + n].adc(carry, Limb::ZERO).0;
+        }
+
+        q[j] = q_hat;
+    }
+}
+
+/// 通用长除法 a = q * b + r，a、b 均为小端 limb 切片且 b 非零
+///
+/// 要求 `a.len() >= b.len()`，q 的长度为 `a.len()`，r 的长度为 `b.len()`，
+/// buf 为长度不小于 `a.len() + b.len() + 1` 的临时空间
+pub(crate) fn div_rem_limbs(
+    a: &[Limb],
+    b: &[Limb],
+    q: &mut [Limb],
+    r: &mut [Limb],
+    buf: &mut [Limb],
+) {
+    let n = b.len() - b.iter().rev().take_while(|l| l.is_zero()).count();
+    assert!(n > 0, "attempt to divide by zero");
+    assert!(a.len() >= b.len());
+
+    // 规范化: 左移使除数的最高位为 1
+    let shift = b[n - 1].0.leading_zeros();
+    let (u, rest) = buf.split_at_mut(a.len() + 1);
+    let v = &mut rest[..n];
+    shl_limbs(&b[..n], shift, v);
+    let top = shl_limbs(a, shift, &mut u[..a.len()]);
+    u[a.len()] = top;
+
+    let m = a.len() - n;
+    q.fill(Limb::ZERO);
+    div_rem_normalized(u, v, &mut q[..=m]);
+
+    r.fill(Limb::ZERO);
+    shr_limbs(&u[..n], shift, &mut r[..n]);
+}
+
+/// dst = src << shift (shift < 64)，返回移出的高位
+#[inline(always)]
+fn shl_limbs(src: &[Limb], shift: u32, dst: &mut [Limb]) -> Limb {
+    if shift == 0 {
+        dst.copy_from_slice(src);
+        return Limb::ZERO;
+    }
+    let mut carry = Limb::ZERO;
+    for i in 0..src.len() {
+        dst[i] = Limb((src[i].0 << shift) | carry.0);
+        carry = Limb(src[i].0 >> (Limb::BITS as u32 - shift));
+    }
+    carry
+}
+
+/// dst = src >> shift (shift < 64)
+#[inline(always)]
+fn shr_limbs(src: &[Limb], shift: u32, dst: &mut [Limb]) {
+    if shift == 0 {
+        dst.copy_from_slice(src);
+        return;
+    }
+    let mut carry = Limb::ZERO;
+    for i in (0..src.len()).rev() {
+        dst[i] = Limb((src[i].0 >> shift) | carry.0);
+        carry = Limb(src[i].0 << (Limb::BITS as u32 - shift));
+    }
+}
+
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+    /// 长除法 (Knuth 算法 D)，运行时间取决于除数的有效 limb 数
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert_ne!(rhs, &Self::ZERO);
+
+        let mut quo = Self::ZERO;
+        let mut rem = Self::ZERO;
+        let mut buf = [[Limb::ZERO; LIMBS]; 3];
+        div_rem_limbs(
+            &self.limbs,
+            &rhs.limbs,
+            &mut quo.limbs,
+            &mut rem.limbs,
+            buf.as_flattened_mut(),
+        );
+
+        (quo, rem)
+    }
+
+    #[inline(always)]
+    /// 除数为单个 limb 的短除法
+    pub fn div_rem_limb(&self, rhs: Limb) -> (Self, Limb) {
+        assert!(rhs.is_nonzero(), "attempt to divide by zero");
+        let mut quo = Self::ZERO;
+        let mut rem = Limb::ZERO;
+
+        for i in (0..LIMBS).rev() {
+            let (q, r) = Limb::div_wide(rem, self.limbs[i], rhs);
+            quo.limbs[i] = q;
+            rem = r;
+        }
+
+        (quo, rem)
+    }
+
+    #[inline(always)]
+    /// 逐位移位减法，运行时间只取决于除数的位数，与被除数无关
+    pub fn div_rem_binary(&self, rhs: &Self) -> (Self, Self) {
         assert_ne!(rhs, &Self::ZERO);
 
         let divisor = *rhs;
@@ -16,10 +178,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         loop {
             let (r, borrow) = rem.sbb(&c, Limb::ZERO);
-            if borrow.is_zero() {
-                rem = r;
-                quo = quo.bitor(&Self::ONE)
-            }
+            let no_borrow = !Choice::from_mask(borrow.0);
+            rem = Self::ct_select(&rem, &r, no_borrow);
+            quo.limbs[0].0 |= no_borrow.to_bit();
             if bd == 0 {
                 break;
             }
@@ -28,9 +189,6 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             quo = quo.wrapping_shl1();
         }
 
-        #[cfg(test)]
-        assert_eq!(*self, quo * &divisor + &rem);
-
         (quo, rem)
     }
 }
@@ -47,7 +205,8 @@ impl<const LIMBS: usize> Div<&Uint<LIMBS>> for Uint<LIMBS> {
 mod test {
     use rand::{thread_rng, Rng};
 
-    use crate::num::uint::U128;
+    use crate::num::limb::Limb;
+    use crate::num::uint::{U128, U256, U512};
 
     #[test]
     fn test_div() {
@@ -66,4 +225,76 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_div_rem_limb() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let b: u64 = rng.gen::<u64>().max(1);
+            let (q, r) = U128::from_u128(a).div_rem_limb(Limb(b));
+            assert_eq!(U128::from_u128(a / b as u128), q, "a: {}, b: {}", a, b);
+            assert_eq!(Limb((a % b as u128) as u64), r, "a: {}, b: {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_div_rem_u128() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let b: u128 = (rng.gen::<u128>() >> rng.gen_range(0..128)).max(1);
+            let (q, r) = U128::from_u128(a).div_rem(&U128::from_u128(b));
+            assert_eq!(U128::from_u128(a / b), q, "a: {}, b: {}", a, b);
+            assert_eq!(U128::from_u128(a % b), r, "a: {}, b: {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_div_rem_matches_binary() {
+        let mut rng = thread_rng();
+        for _ in 0..300 {
+            let a = U512::rand(&mut rng).wrapping_shr(rng.gen_range(0..512));
+            let b = U512::rand(&mut rng).wrapping_shr(rng.gen_range(0..512));
+            if b.is_zero() {
+                continue;
+            }
+            assert_eq!(b.div_rem_binary(&b), b.div_rem(&b));
+            assert_eq!(a.div_rem_binary(&b), a.div_rem(&b), "a: {}, b: {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_div_rem_identity() {
+        // a = q * b + r 且 r < b
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a = U512::rand(&mut rng).wrapping_shr(rng.gen_range(0..512));
+            let b = U512::rand(&mut rng).wrapping_shr(rng.gen_range(0..512));
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b);
+            assert!(r < b, "a: {}, b: {}", a, b);
+            assert_eq!(a, q * &b + &r, "a: {}, b: {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_div_rem_correction() {
+        // 商估计需要修正或加回的情况: 除数高位全 1、被除数高位与除数高位相等
+        let b = U256::new([Limb(1), Limb::ZERO, Limb::MAX, Limb::MAX]);
+        let cases = [
+            U256::MAX,
+            U256::new([Limb::ZERO, Limb::ZERO, Limb::MAX, Limb::MAX]),
+            U256::new([Limb::ZERO, Limb::MAX, Limb(u64::MAX - 1), Limb::MAX]),
+        ];
+        for a in cases {
+            assert_eq!(a.div_rem_binary(&b), a.div_rem(&b), "a: {}", a);
+        }
+
+        let b = U256::new([Limb::MAX, Limb::MAX, Limb(1 << 63), Limb::ZERO]);
+        let a = U256::new([Limb::ZERO, Limb::ZERO, Limb::ZERO, Limb(1 << 63)]);
+        assert_eq!(a.div_rem_binary(&b), a.div_rem(&b));
+    }
 }
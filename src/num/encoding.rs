@@ -0,0 +1,233 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::num::error::OverflowError;
+use crate::num::limb::Limb;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 小端序下第 i 个字节，超出范围时为 0
+    #[inline(always)]
+    fn le_byte(&self, i: usize) -> u8 {
+        if i < Self::BYTES {
+            (self.limbs[i / Limb::BYTES].0 >> (8 * (i % Limb::BYTES))) as u8
+        } else {
+            0
+        }
+    }
+
+    /// 有效字节数，即去掉高位零字节后的长度
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        self.bits().div_ceil(8)
+    }
+
+    /// Panics if `bytes.len() != Self::BYTES`.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::BYTES, "from_be_bytes: length mismatch");
+        let mut ret = Self::ZERO;
+        for (limb, chunk) in ret.limbs.iter_mut().zip(bytes.rchunks_exact(Limb::BYTES)) {
+            *limb = Limb(u64::from_be_bytes(chunk.try_into().unwrap()));
+        }
+        ret
+    }
+
+    /// Panics if `bytes.len() != Self::BYTES`.
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::BYTES, "from_le_bytes: length mismatch");
+        let mut ret = Self::ZERO;
+        for (limb, chunk) in ret.limbs.iter_mut().zip(bytes.chunks_exact(Limb::BYTES)) {
+            *limb = Limb(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        ret
+    }
+
+    /// 任意长度的大端序字节串，不足 `Self::BYTES` 时高位补零，
+    /// 超出部分必须全为零
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, OverflowError> {
+        let mut ret = Self::ZERO;
+        for (i, &b) in bytes.iter().rev().enumerate() {
+            if i >= Self::BYTES {
+                if b != 0 {
+                    return Err(OverflowError);
+                }
+                continue;
+            }
+            ret.limbs[i / Limb::BYTES].0 |= (b as u64) << (8 * (i % Limb::BYTES));
+        }
+        Ok(ret)
+    }
+
+    /// 任意长度的小端序字节串，不足 `Self::BYTES` 时高位补零，
+    /// 超出部分必须全为零
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, OverflowError> {
+        let mut ret = Self::ZERO;
+        for (i, &b) in bytes.iter().enumerate() {
+            if i >= Self::BYTES {
+                if b != 0 {
+                    return Err(OverflowError);
+                }
+                continue;
+            }
+            ret.limbs[i / Limb::BYTES].0 |= (b as u64) << (8 * (i % Limb::BYTES));
+        }
+        Ok(ret)
+    }
+
+    /// 以大端序写满 `out`，长度大于 `Self::BYTES` 时高位补零
+    pub fn write_be_bytes(&self, out: &mut [u8]) -> Result<(), OverflowError> {
+        if out.len() < self.byte_len() {
+            return Err(OverflowError);
+        }
+        for (i, b) in out.iter_mut().rev().enumerate() {
+            *b = self.le_byte(i);
+        }
+        Ok(())
+    }
+
+    /// 以小端序写满 `out`，长度大于 `Self::BYTES` 时高位补零
+    pub fn write_le_bytes(&self, out: &mut [u8]) -> Result<(), OverflowError> {
+        if out.len() < self.byte_len() {
+            return Err(OverflowError);
+        }
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = self.le_byte(i);
+        }
+        Ok(())
+    }
+
+    /// 长度恰为 `Self::BYTES` 的大端序字节串
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(Self::BYTES);
+        for limb in self.limbs.iter().rev() {
+            ret.extend_from_slice(&limb.0.to_be_bytes());
+        }
+        ret
+    }
+
+    /// 长度恰为 `Self::BYTES` 的小端序字节串
+    #[cfg(feature = "alloc")]
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(Self::BYTES);
+        for limb in self.limbs.iter() {
+            ret.extend_from_slice(&limb.0.to_le_bytes());
+        }
+        ret
+    }
+
+    /// 补零或截去高位零字节到 `len` 字节的大端序字节串
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes_padded(&self, len: usize) -> Result<Vec<u8>, OverflowError> {
+        let mut ret = alloc::vec![0u8; len];
+        self.write_be_bytes(&mut ret)?;
+        Ok(ret)
+    }
+
+    /// 补零或截去高位零字节到 `len` 字节的小端序字节串
+    #[cfg(feature = "alloc")]
+    pub fn to_le_bytes_padded(&self, len: usize) -> Result<Vec<u8>, OverflowError> {
+        let mut ret = alloc::vec![0u8; len];
+        self.write_le_bytes(&mut ret)?;
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::error::OverflowError;
+    use crate::num::uint::{U128, U256, U64};
+
+    #[test]
+    fn test_write_bytes_roundtrip() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let x = U128::from_u128(a);
+            let mut buf = [0u8; 16];
+            x.write_be_bytes(&mut buf).unwrap();
+            assert_eq!(a.to_be_bytes(), buf);
+            assert_eq!(x, U128::from_be_bytes(&buf));
+            x.write_le_bytes(&mut buf).unwrap();
+            assert_eq!(a.to_le_bytes(), buf);
+            assert_eq!(x, U128::from_le_bytes(&buf));
+
+            let y = U256::rand(&mut rng);
+            let mut buf = [0u8; 32];
+            y.write_be_bytes(&mut buf).unwrap();
+            assert_eq!(y, U256::from_be_bytes(&buf));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bytes_roundtrip() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let x = U128::from_u128(a);
+            assert_eq!(a.to_be_bytes().to_vec(), x.to_be_bytes());
+            assert_eq!(a.to_le_bytes().to_vec(), x.to_le_bytes());
+            assert_eq!(x, U128::from_be_bytes(&a.to_be_bytes()));
+            assert_eq!(x, U128::from_le_bytes(&a.to_le_bytes()));
+
+            let y = U256::rand(&mut rng);
+            assert_eq!(y, U256::from_be_bytes(&y.to_be_bytes()));
+            assert_eq!(y, U256::from_le_bytes(&y.to_le_bytes()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_be_bytes_length() {
+        U64::from_be_bytes(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        assert_eq!(Ok(U64::from_u64(0x0102)), U64::from_be_slice(&[1, 2]));
+        assert_eq!(Ok(U64::from_u64(0x0201)), U64::from_le_slice(&[1, 2]));
+        assert_eq!(Ok(U64::ZERO), U64::from_be_slice(&[]));
+
+        // 超出部分为零时允许
+        let mut be = [0u8; 12];
+        be[11] = 7;
+        assert_eq!(Ok(U64::from_u64(7)), U64::from_be_slice(&be));
+        be[3] = 1;
+        assert_eq!(Err(OverflowError), U64::from_be_slice(&be));
+
+        let mut le = [0u8; 12];
+        le[0] = 7;
+        assert_eq!(Ok(U64::from_u64(7)), U64::from_le_slice(&le));
+        le[8] = 1;
+        assert_eq!(Err(OverflowError), U64::from_le_slice(&le));
+    }
+
+    #[test]
+    fn test_write_bytes_padded() {
+        let x = U128::from_u64(0x0a0b0c);
+        let mut out = [0xffu8; 20];
+        x.write_le_bytes(&mut out).unwrap();
+        assert_eq!(Ok(x), U128::from_le_slice(&out));
+        x.write_be_bytes(&mut out).unwrap();
+        assert_eq!(Ok(x), U128::from_be_slice(&out));
+
+        let mut short = [0u8; 3];
+        x.write_be_bytes(&mut short).unwrap();
+        assert_eq!([0x0a, 0x0b, 0x0c], short);
+        assert_eq!(Err(OverflowError), x.write_le_bytes(&mut short[..2]));
+        assert_eq!(Ok(()), U64::ZERO.write_be_bytes(&mut []));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_padded() {
+        let x = U128::from_u64(0x0a0b0c);
+        assert_eq!(Ok(vec![0, 0x0a, 0x0b, 0x0c]), x.to_be_bytes_padded(4));
+        assert_eq!(Ok(vec![0x0c, 0x0b, 0x0a]), x.to_le_bytes_padded(3));
+        assert_eq!(Err(OverflowError), x.to_be_bytes_padded(2));
+        assert_eq!(Ok(vec![]), U64::ZERO.to_be_bytes_padded(0));
+    }
+}
@@ -0,0 +1,40 @@
+use core::fmt;
+
+/// 字符串解析错误
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// 不含任何数字
+    Empty,
+    /// 出现当前进制下无效的字符
+    InvalidDigit,
+    /// 小端序十六进制的数字个数不是偶数，无法按字节划分
+    InvalidLength,
+    /// 数值超出 `Uint::BITS` 位
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ParseError::Empty => "cannot parse integer from empty string",
+            ParseError::InvalidDigit => "invalid digit found in string",
+            ParseError::InvalidLength => "hex string does not contain a whole number of bytes",
+            ParseError::Overflow => "number too large to fit in target type",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// 字节串表示的数值超出 `Uint::BITS` 位，或无法写入给定长度的缓冲区
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("integer does not fit in the given number of bytes")
+    }
+}
+
+impl core::error::Error for OverflowError {}
@@ -1,10 +1,18 @@
+use core::fmt;
+
+use crate::num::limb::Limb;
 use crate::num::uint::Uint;
-use std::fmt;
+use crate::num::wide::Wide;
+
+/// 10^19，单个 limb 能容纳的最大 10 的幂
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 19;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
+    #[cfg(feature = "alloc")]
     #[inline(always)]
-    pub(crate) fn to_binary_string(self) -> String {
-        let mut result = String::with_capacity(LIMBS);
+    pub fn to_binary_string(self) -> alloc::string::String {
+        let mut result = alloc::string::String::with_capacity(Self::BITS);
 
         for i in (0..LIMBS).rev() {
             let limb = self.limbs[i];
@@ -15,28 +23,128 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 }
 
-impl<const LIMBS: usize> fmt::Display for Uint<LIMBS> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = String::new();
-        let mut current = *self;
+/// 将 limbs 原地反复除以 10^19，十进制数字从 `buf` 末尾向前写入
+pub(crate) fn fmt_decimal(
+    is_nonnegative: bool,
+    limbs: &mut [Limb],
+    buf: &mut [u8],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let mut pos = buf.len();
 
-        while !current.is_zero() {
-            let (next, remainder) = current.div_rem(&Self::TEN);
-            result.push_str(remainder.limbs[0].0.to_string().as_str());
-            current = next;
+    // 每次除以 10^19 取出 19 位十进制数，最高的一段不补前导零
+    loop {
+        let mut rem = Limb::ZERO;
+        for limb in limbs.iter_mut().rev() {
+            (*limb, rem) = Limb::div_wide(rem, *limb, Limb(DECIMAL_CHUNK));
+        }
+        let mut chunk = rem.0;
+        let last = limbs.iter().all(|limb| limb.is_zero());
+        for _ in 0..DECIMAL_CHUNK_DIGITS {
+            pos -= 1;
+            buf[pos] = b'0' + (chunk % 10) as u8;
+            chunk /= 10;
+            if last && chunk == 0 {
+                break;
+            }
         }
+        if last {
+            break;
+        }
+    }
 
-        if result.is_empty() {
-            result.push('0');
+    let digits = core::str::from_utf8(&buf[pos..]).expect("decimal digits are ascii");
+    f.pad_integral(is_nonnegative, "", digits)
+}
+
+/// 进制为 2^log2 的格式化，每个数字取 log2 位，可跨越 limb 边界 (八进制)
+pub(crate) fn fmt_pow2(
+    limbs: &[Limb],
+    log2: usize,
+    upper: bool,
+    prefix: &str,
+    buf: &mut [u8],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let alphabet: &[u8; 16] = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let bits = limbs.len() * Limb::BITS;
+    let mask = (1u64 << log2) - 1;
+    let mut pos = buf.len();
+    let mut offset = 0;
+
+    loop {
+        let (i, shift) = (offset / Limb::BITS, offset % Limb::BITS);
+        let mut digit = limbs[i].0 >> shift;
+        if shift + log2 > Limb::BITS && i + 1 < limbs.len() {
+            digit |= limbs[i + 1].0 << (Limb::BITS - shift);
         }
+        pos -= 1;
+        buf[pos] = alphabet[(digit & mask) as usize];
+        offset += log2;
 
-        result.chars().rev().collect::<String>().fmt(f)
+        // 剩余的高位全为零时停止，至少输出一个数字
+        if offset >= bits || limbs_shr_is_zero(limbs, offset) {
+            break;
+        }
     }
+
+    let digits = core::str::from_utf8(&buf[pos..]).expect("digits are ascii");
+    f.pad_integral(true, prefix, digits)
 }
 
+/// limbs >> offset 是否为零
+#[inline(always)]
+fn limbs_shr_is_zero(limbs: &[Limb], offset: usize) -> bool {
+    let (i, shift) = (offset / Limb::BITS, offset % Limb::BITS);
+    limbs[i].0 >> shift == 0 && limbs[i + 1..].iter().all(|limb| limb.is_zero())
+}
+
+macro_rules! impl_fmt {
+    ($ty:ident, $digits_per_limb:expr, |$this:ident| $limbs:expr) => {
+        impl<const LIMBS: usize> fmt::Display for $ty<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // 缓冲区按二进制的长度分配，十进制所需更短
+                let mut buf = [[0u8; $digits_per_limb]; LIMBS];
+                let $this = self;
+                let mut limbs = $limbs;
+                fmt_decimal(true, limbs.as_flattened_mut(), buf.as_flattened_mut(), f)
+            }
+        }
+
+        impl_fmt!(@pow2 $ty, $digits_per_limb, |$this| $limbs, fmt::LowerHex, 4, false, "0x");
+        impl_fmt!(@pow2 $ty, $digits_per_limb, |$this| $limbs, fmt::UpperHex, 4, true, "0x");
+        impl_fmt!(@pow2 $ty, $digits_per_limb, |$this| $limbs, fmt::Octal, 3, false, "0o");
+        impl_fmt!(@pow2 $ty, $digits_per_limb, |$this| $limbs, fmt::Binary, 1, false, "0b");
+    };
+    (@pow2 $ty:ident, $digits_per_limb:expr, |$this:ident| $limbs:expr, $trait:path, $log2:expr, $upper:expr, $prefix:expr) => {
+        impl<const LIMBS: usize> $trait for $ty<LIMBS> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [[0u8; $digits_per_limb]; LIMBS];
+                let $this = self;
+                let limbs = $limbs;
+                fmt_pow2(
+                    limbs.as_flattened(),
+                    $log2,
+                    $upper,
+                    $prefix,
+                    buf.as_flattened_mut(),
+                    f,
+                )
+            }
+        }
+    };
+}
+
+impl_fmt!(Uint, 64, |this| [this.limbs]);
+impl_fmt!(Wide, 128, |this| [this.low.limbs, this.high.limbs]);
+
 #[cfg(test)]
 mod test {
-    use crate::num::uint::U128;
+    use crate::num::uint::{U128, U256, U64};
     use rand::{thread_rng, Rng};
 
     #[test]
@@ -48,6 +156,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_to_string_wide() {
+        // 10^19 的整数倍与边界
+        assert_eq!("0", U128::ZERO.to_string());
+        assert_eq!(
+            "10000000000000000000",
+            U128::from_u64(10_000_000_000_000_000_000).to_string()
+        );
+        assert_eq!(u64::MAX.to_string(), U64::MAX.to_string());
+        assert_eq!(u128::MAX.to_string(), U128::MAX.to_string());
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            U256::MAX.to_string()
+        );
+        assert_eq!("  42", format!("{:>4}", U128::from_u64(42)));
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_to_binary_string() {
         assert_eq!(
@@ -59,4 +185,55 @@ mod test {
         U128::from_u64(u64::MAX).to_binary_string()
         );
     }
+
+    #[test]
+    fn test_radix_fmt() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let x = U128::from_u128(a);
+            assert_eq!(format!("{:x}", a), format!("{:x}", x));
+            assert_eq!(format!("{:X}", a), format!("{:X}", x));
+            assert_eq!(format!("{:o}", a), format!("{:o}", x));
+            assert_eq!(format!("{:b}", a), format!("{:b}", x));
+        }
+        assert_eq!(format!("{:o}", u128::MAX), format!("{:o}", U128::MAX));
+        assert_eq!("0", format!("{:x}", U256::ZERO));
+    }
+
+    #[test]
+    fn test_radix_fmt_flags() {
+        let a = 0xbeefu128;
+        let x = U128::from_u128(a);
+        for (expect, actual) in [
+            (format!("{:#x}", a), format!("{:#x}", x)),
+            (format!("{:#010X}", a), format!("{:#010X}", x)),
+            (format!("{:*^12o}", a), format!("{:*^12o}", x)),
+            (format!("{:#b}", a), format!("{:#b}", x)),
+            (format!("{:>8x}", a), format!("{:>8x}", x)),
+            (format!("{:08}", a), format!("{:08}", x)),
+            (format!("{:+}", a), format!("{:+}", x)),
+        ] {
+            assert_eq!(expect, actual);
+        }
+    }
+
+    #[test]
+    fn test_wide_fmt() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let b: u64 = rng.gen();
+            let w = U64::from_u64(a).split_mul(&U64::from_u64(b));
+            let c = a as u128 * b as u128;
+            assert_eq!(c.to_string(), w.to_string());
+            assert_eq!(format!("{:#x}", c), format!("{:#x}", w));
+            assert_eq!(format!("{:X}", c), format!("{:X}", w));
+            assert_eq!(format!("{:o}", c), format!("{:o}", w));
+            assert_eq!(format!("{:b}", c), format!("{:b}", w));
+        }
+        let max = U256::MAX.split_mul(&U256::ONE);
+        assert_eq!(U256::MAX.to_string(), max.to_string());
+        assert_eq!(format!("{:x}", U256::MAX), format!("{:x}", max));
+    }
 }
@@ -1,20 +1,55 @@
+use crate::num::error::OverflowError;
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
+
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) const fn from_u64(n: u64) -> Self {
+    pub const fn from_u64(n: u64) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
         limbs[0].0 = n;
         Self { limbs }
     }
 
+    /// 由小端序的 64 位字构造
     #[inline(always)]
-    pub(crate) const fn from_u128(n: u128) -> Self {
+    pub const fn from_words(words: [u64; LIMBS]) -> Self {
+        let mut limbs = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = Limb(words[i]);
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    #[inline(always)]
+    pub const fn from_u128(n: u128) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
         limbs[0].0 = n as u64;
         limbs[1].0 = (n >> Limb::BITS) as u64;
         Self { limbs }
     }
+
+    /// 转换为 T 个 limb: 变宽时高位补零，变窄时截去高位 limb
+    #[inline(always)]
+    pub const fn resize<const T: usize>(&self) -> Uint<T> {
+        let mut limbs = [Limb::ZERO; T];
+        let mut i = 0;
+        while i < LIMBS && i < T {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        Uint { limbs }
+    }
+
+    /// Returns `None` if the value does not fit in `T` limbs.
+    #[inline(always)]
+    pub fn checked_resize<const T: usize>(&self) -> Option<Uint<T>> {
+        if LIMBS > T && self.limbs[T..].iter().any(|l| l.is_nonzero()) {
+            return None;
+        }
+        Some(self.resize())
+    }
 }
 
 impl<const LIMBS: usize> From<u64> for Uint<LIMBS> {
@@ -29,9 +64,30 @@ impl<const LIMBS: usize> From<u128> for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> TryFrom<Uint<LIMBS>> for u64 {
+    type Error = OverflowError;
+
+    fn try_from(n: Uint<LIMBS>) -> Result<Self, Self::Error> {
+        n.checked_resize::<1>()
+            .map(|n| n.limbs[0].0)
+            .ok_or(OverflowError)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<Uint<LIMBS>> for u128 {
+    type Error = OverflowError;
+
+    fn try_from(n: Uint<LIMBS>) -> Result<Self, Self::Error> {
+        n.checked_resize::<2>()
+            .map(|n| (n.limbs[1].0 as u128) << Limb::BITS | n.limbs[0].0 as u128)
+            .ok_or(OverflowError)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::num::uint::U128;
+    use crate::num::error::OverflowError;
+    use crate::num::uint::{U1024, U128, U2048, U256, U512, U64};
     use rand::{thread_rng, Rng};
 
     #[test]
@@ -43,4 +99,38 @@ mod test {
             assert_eq!(v2.to_string(), v.to_string());
         }
     }
+
+    #[test]
+    fn test_resize() {
+        let mut rng = thread_rng();
+        let a = U256::rand(&mut rng);
+        let b: U512 = a.resize();
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!(Some(a), b.checked_resize());
+        assert_eq!(a, b.resize());
+
+        // 变窄时截去高位
+        let low: U128 = a.resize();
+        assert_eq!(a.rem_2k(128), low.resize());
+        assert_eq!(None, U256::MAX.checked_resize::<2>());
+        assert_eq!(Some(U128::MAX), U128::MAX.resize::<4>().checked_resize());
+
+        // 两个 U1024 的乘积
+        let p = U1024::rand(&mut rng);
+        let q = U1024::rand(&mut rng);
+        let n: U2048 = p.split_mul(&q).to_uint();
+        assert_eq!(n, p.resize::<32>() * &q.resize());
+    }
+
+    #[test]
+    fn test_try_from_uint() {
+        let mut rng = thread_rng();
+        let v: u128 = rng.gen();
+        assert_eq!(Ok(v), u128::try_from(U256::from_u128(v)));
+        assert_eq!(Ok(v as u64), u64::try_from(U64::from_u64(v as u64)));
+        assert_eq!(Ok(v as u64), u64::try_from(U256::from_u64(v as u64)));
+        assert_eq!(Ok(u64::MAX as u128), u128::try_from(U64::MAX));
+        assert_eq!(Err(OverflowError), u64::try_from(U128::MAX));
+        assert_eq!(Err(OverflowError), u128::try_from(U256::MAX));
+    }
 }
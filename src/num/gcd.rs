@@ -1,4 +1,4 @@
-use std::cmp::{min, Ordering};
+use core::cmp::{min, Ordering};
 
 use crate::num::uint::Uint;
 
@@ -0,0 +1,426 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
+use crate::num::error::ParseError;
+use crate::num::fmt::fmt_decimal;
+use crate::num::macros::forward_binop;
+use crate::num::uint::Uint;
+
+/// 符号-绝对值表示的有符号整数，取值范围为 `[-(2^BITS - 1), 2^BITS - 1]`
+///
+/// 零总是非负的，因此 `-0` 与 `0` 表示相同
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Int<const LIMBS: usize> {
+    negative: bool,
+    magnitude: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> Int<LIMBS> {
+    pub const ZERO: Self = Self::from_uint(Uint::ZERO);
+    pub const ONE: Self = Self::from_uint(Uint::ONE);
+    pub const MINUS_ONE: Self = Self {
+        negative: true,
+        magnitude: Uint::ONE,
+    };
+    pub const MAX: Self = Self::from_uint(Uint::MAX);
+    pub const MIN: Self = Self {
+        negative: true,
+        magnitude: Uint::MAX,
+    };
+
+    /// 由符号与绝对值构造，绝对值为零时忽略符号
+    #[inline(always)]
+    pub fn new(negative: bool, magnitude: Uint<LIMBS>) -> Self {
+        Self {
+            negative: negative && magnitude.is_nonzero(),
+            magnitude,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn from_uint(n: Uint<LIMBS>) -> Self {
+        Self {
+            negative: false,
+            magnitude: n,
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_i64(n: i64) -> Self {
+        Self::new(n < 0, Uint::from_u64(n.unsigned_abs()))
+    }
+
+    #[inline(always)]
+    pub fn from_i128(n: i128) -> Self {
+        Self::new(n < 0, Uint::from_u128(n.unsigned_abs()))
+    }
+
+    /// Returns `None` if `self` is negative.
+    #[inline(always)]
+    pub fn to_uint(&self) -> Option<Uint<LIMBS>> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude)
+        }
+    }
+
+    #[inline(always)]
+    pub fn unsigned_abs(&self) -> Uint<LIMBS> {
+        self.magnitude
+    }
+
+    #[inline(always)]
+    pub fn abs(&self) -> Self {
+        Self::from_uint(self.magnitude)
+    }
+
+    /// -1, 0 或 1
+    #[inline(always)]
+    pub fn signum(&self) -> Self {
+        if self.negative {
+            Self::MINUS_ONE
+        } else if self.magnitude.is_zero() {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[inline(always)]
+    pub fn is_positive(&self) -> bool {
+        !self.negative && self.magnitude.is_nonzero()
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        if self.negative == rhs.negative {
+            let (magnitude, overflow) = self.magnitude.overflowing_add(&rhs.magnitude);
+            if overflow {
+                return None;
+            }
+            return Some(Self::new(self.negative, magnitude));
+        }
+
+        // 异号时以绝对值较大者的符号为结果符号
+        if self.magnitude >= rhs.magnitude {
+            Some(Self::new(
+                self.negative,
+                self.magnitude.wrapping_sub(&rhs.magnitude),
+            ))
+        } else {
+            Some(Self::new(
+                rhs.negative,
+                rhs.magnitude.wrapping_sub(&self.magnitude),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&-rhs)
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (magnitude, overflow) = self.magnitude.overflowing_mul(&rhs.magnitude);
+        if overflow {
+            return None;
+        }
+        Some(Self::new(self.negative != rhs.negative, magnitude))
+    }
+
+    /// 截断除法: 商向零取整，余数与被除数同号，与 Rust 原生整数的 `/` 和 `%` 一致
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem_trunc(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (q, r) = self.magnitude.div_rem(&rhs.magnitude);
+        (
+            Self::new(self.negative != rhs.negative, q),
+            Self::new(self.negative, r),
+        )
+    }
+
+    /// 向下取整除法: 商向负无穷取整，余数与除数同号
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn div_rem_floor(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.div_rem_trunc(rhs);
+        // 余数非零且与除数异号时，商减一，余数加上除数
+        if !r.is_zero() && r.negative != rhs.negative {
+            // |q| < 2^BITS - 1 在此情形下总是成立，不会溢出
+            (q - Self::ONE, r + rhs)
+        } else {
+            (q, r)
+        }
+    }
+
+    /// Panics if `rhs` is zero.
+    #[inline(always)]
+    pub fn div_floor(&self, rhs: &Self) -> Self {
+        self.div_rem_floor(rhs).0
+    }
+
+    /// Panics if `rhs` is zero.
+    #[inline(always)]
+    pub fn rem_floor(&self, rhs: &Self) -> Self {
+        self.div_rem_floor(rhs).1
+    }
+
+    /// 可选的前导 `+` 或 `-`，其后的数字按 `Uint::from_str_radix` 解析
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.starts_with('+') {
+            return Err(ParseError::InvalidDigit);
+        }
+        let magnitude = Uint::from_str_radix(digits, radix)?;
+        Ok(Self::new(negative, magnitude))
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for Int<LIMBS> {
+    fn from(n: Uint<LIMBS>) -> Self {
+        Self::from_uint(n)
+    }
+}
+
+impl<const LIMBS: usize> From<i64> for Int<LIMBS> {
+    fn from(n: i64) -> Self {
+        Self::from_i64(n)
+    }
+}
+
+impl<const LIMBS: usize> From<i128> for Int<LIMBS> {
+    fn from(n: i128) -> Self {
+        Self::from_i128(n)
+    }
+}
+
+impl<const LIMBS: usize> Ord for Int<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Int<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Neg for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn neg(self) -> Self::Output {
+        Int::new(!self.negative, self.magnitude)
+    }
+}
+
+impl<const LIMBS: usize> Neg for Int<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<const LIMBS: usize> Add for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("attempted to add with overflow")
+    }
+}
+
+impl<const LIMBS: usize> Sub for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempted to subtract with overflow")
+    }
+}
+
+impl<const LIMBS: usize> Mul for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempted to multiply with overflow")
+    }
+}
+
+/// 截断除法，见 [`Int::div_rem_trunc`]
+impl<const LIMBS: usize> Div for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem_trunc(rhs).0
+    }
+}
+
+/// 截断取余，见 [`Int::div_rem_trunc`]
+impl<const LIMBS: usize> Rem for &Int<LIMBS> {
+    type Output = Int<LIMBS>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem_trunc(rhs).1
+    }
+}
+
+forward_binop!([const LIMBS: usize] Int<LIMBS>; Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl<const LIMBS: usize> fmt::Display for Int<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [[0u8; 20]; LIMBS];
+        let mut limbs = self.magnitude.limbs;
+        fmt_decimal(!self.negative, &mut limbs, buf.as_flattened_mut(), f)
+    }
+}
+
+impl<const LIMBS: usize> FromStr for Int<LIMBS> {
+    type Err = ParseError;
+
+    /// 十进制
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+pub type I8192 = Int<128>;
+pub type I4096 = Int<64>;
+pub type I2048 = Int<32>;
+pub type I1024 = Int<16>;
+pub type I512 = Int<8>;
+pub type I256 = Int<4>;
+pub type I128 = Int<2>;
+pub type I64 = Int<1>;
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::error::ParseError;
+    use crate::num::int::{Int, I128, I64};
+    use crate::num::uint::{U128, U64};
+
+    #[test]
+    fn test_arith() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: i64 = rng.gen();
+            let b: i64 = rng.gen();
+            let (x, y) = (I128::from_i64(a), I128::from_i64(b));
+            let (a, b) = (a as i128, b as i128);
+            assert_eq!(I128::from_i128(a + b), x + y);
+            assert_eq!(I128::from_i128(a - b), x - y);
+            assert_eq!(I128::from_i128(a * b), x * y);
+            assert_eq!(I128::from_i128(-a), -x);
+            assert_eq!(I128::from_i128(a.abs()), x.abs());
+            assert_eq!(I128::from_i128(a.signum()), x.signum());
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: i128 = rng.gen::<i64>() as i128;
+            let b: i128 = rng.gen::<i32>() as i128;
+            if b == 0 {
+                continue;
+            }
+            let (x, y) = (I128::from_i128(a), I128::from_i128(b));
+            assert_eq!(I128::from_i128(a / b), x / y);
+            assert_eq!(I128::from_i128(a % b), x % y);
+            let floor = a / b - (a % b != 0 && (a < 0) != (b < 0)) as i128;
+            assert_eq!(I128::from_i128(floor), x.div_floor(&y));
+            let r = x.rem_floor(&y);
+            assert!(r.is_zero() || r.is_negative() == y.is_negative());
+            assert_eq!(x, x.div_floor(&y) * y + r);
+        }
+
+        // 各符号组合下的向下取整
+        for (a, b, q, r) in [
+            (7, 2, 3, 1),
+            (-7, 2, -4, 1),
+            (7, -2, -4, -1),
+            (-7, -2, 3, -1),
+        ] {
+            let (x, y) = (I64::from_i64(a), I64::from_i64(b));
+            assert_eq!((I64::from_i64(q), I64::from_i64(r)), x.div_rem_floor(&y));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_by_zero() {
+        let _ = I64::ONE / I64::ZERO;
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(None, I64::MAX.checked_add(&I64::ONE));
+        assert_eq!(None, I64::MIN.checked_sub(&I64::ONE));
+        assert_eq!(None, I64::MAX.checked_mul(&I64::from_i64(-2)));
+        assert_eq!(Some(I64::ZERO), I64::MAX.checked_add(&I64::MIN));
+        assert_eq!(I64::MIN, -I64::MAX);
+    }
+
+    #[test]
+    fn test_zero_sign() {
+        let zero = I64::new(true, U64::ZERO);
+        assert_eq!(I64::ZERO, zero);
+        assert!(!zero.is_negative());
+        assert_eq!(I64::ZERO, -I64::ZERO);
+        assert_eq!(I64::ZERO, I64::from_i64(5) + I64::from_i64(-5));
+    }
+
+    #[test]
+    fn test_uint_conversion() {
+        let n = U128::from_u128(12345);
+        assert_eq!(Some(n), Int::from(n).to_uint());
+        assert_eq!(None, (-Int::from(n)).to_uint());
+        assert_eq!(n, (-Int::from(n)).unsigned_abs());
+    }
+
+    #[test]
+    fn test_fmt_parse() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: i128 = rng.gen::<i128>() >> 1;
+            let x = I128::from_i128(a);
+            assert_eq!(a.to_string(), x.to_string());
+            assert_eq!(Ok(x), a.to_string().parse());
+        }
+        assert_eq!("-0042", format!("{:05}", I64::from_i64(-42)));
+        assert_eq!("+7", format!("{:+}", I64::from_i64(7)));
+        assert_eq!(Ok(I64::ZERO), "-0".parse());
+        assert_eq!(Ok(I64::from_i64(-255)), I64::from_str_radix("-ff", 16));
+        assert_eq!(Err(ParseError::InvalidDigit), "-+1".parse::<I64>());
+        assert_eq!(Err(ParseError::Empty), "-".parse::<I64>());
+    }
+}
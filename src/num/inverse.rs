@@ -1,5 +1,5 @@
 use crate::num::uint::Uint;
-use std::ops::Shr;
+use core::ops::Shr;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
@@ -113,7 +113,6 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 }
 
 #[cfg(test)]
-
 mod test {
     use crate::inverse::mod_inverse_2k;
     use crate::num::uint::{U128, U64};
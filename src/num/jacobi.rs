@@ -0,0 +1,69 @@
+use crate::num::uint::Uint;
+
+/// Jacobi 符号 (a / n)，n 为正奇数
+pub fn jacobi<const LIMBS: usize>(a: &Uint<LIMBS>, n: &Uint<LIMBS>) -> i8 {
+    assert!(n.is_odd(), "jacobi symbol requires an odd modulus");
+
+    let mut a = *a % n;
+    let mut n = *n;
+    let mut t = 1i8;
+
+    while a.is_nonzero() {
+        let tz = a.trailing_zeros();
+        a = a.wrapping_shr(tz);
+        // (2 / n) = -1 当且仅当 n ≡ 3, 5 (mod 8)
+        let n_mod_8 = n.limbs[0].0 & 7;
+        if tz % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            t = -t;
+        }
+
+        // 二次互反律
+        core::mem::swap(&mut a, &mut n);
+        if a.limbs[0].0 & 3 == 3 && n.limbs[0].0 & 3 == 3 {
+            t = -t;
+        }
+        a = a % n;
+    }
+
+    if n == Uint::ONE {
+        t
+    } else {
+        0
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use crate::num::jacobi::jacobi;
+    use crate::num::uint::U128;
+
+    /// 按定义计算: 对奇素数 p 使用欧拉判别法，再按 n 的质因数分解相乘
+    fn jacobi_u64(a: u64, n: u64) -> i8 {
+        let mut t = 1i8;
+        for p in crate::prime::prime_factors(n) {
+            let a = a % p;
+            let r = crate::exponent::fast_modular_exponentiation(a, (p - 1) / 2, p);
+            t *= match r {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+        }
+        t
+    }
+
+    #[test]
+    fn test_jacobi() {
+        for n in (1..200u64).step_by(2) {
+            for a in 0..200u64 {
+                assert_eq!(
+                    jacobi_u64(a, n),
+                    jacobi(&U128::from_u64(a), &U128::from_u64(n)),
+                    "a: {} n: {}",
+                    a,
+                    n
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod bit_ops;
 mod cmp;
+mod ct;
+#[cfg(feature = "alloc")]
 mod fmt;
 mod from;
 mod rand;
@@ -19,7 +21,7 @@ impl Limb {
 impl Limb {
     #[inline(always)]
     /// Computes `a + b + carry`, returning the result along with the new carry.
-    pub fn adc(self, rhs: Self, carry: Self) -> (Self, Self) {
+    pub const fn adc(self, rhs: Self, carry: Self) -> (Self, Self) {
         let a = self.0 as u128;
         let b = rhs.0 as u128;
         let carry = carry.0 as u128;
@@ -29,7 +31,7 @@ impl Limb {
 
     #[inline(always)]
     /// Computes `self - (rhs + borrow)`, returning the result along with the new borrow.
-    pub fn sbb(self, rhs: Self, borrow: Self) -> (Self, Self) {
+    pub const fn sbb(self, rhs: Self, borrow: Self) -> (Self, Self) {
         let a = self.0 as u128;
         let b = rhs.0 as u128;
         let borrow = (borrow.0 >> (Self::BITS - 1)) as u128;
@@ -48,6 +50,17 @@ impl Limb {
         (Limb(ret as u64), Limb((ret >> Self::BITS) as u64))
     }
 
+    #[inline(always)]
+    /// Divides the two-limb value `hi:lo` by `rhs`, returning the quotient and remainder.
+    ///
+    /// Requires `hi < rhs` so that the quotient fits in a single limb.
+    pub const fn div_wide(hi: Limb, lo: Limb, rhs: Limb) -> (Limb, Limb) {
+        debug_assert!(hi.0 < rhs.0);
+        let a = ((hi.0 as u128) << Self::BITS) | lo.0 as u128;
+        let b = rhs.0 as u128;
+        (Limb((a / b) as u64), Limb((a % b) as u64))
+    }
+
     #[inline(always)]
     pub fn is_zero(self) -> bool {
         self.0 == 0
@@ -1,5 +1,5 @@
 use crate::num::limb::Limb;
-use std::ops::{BitAnd, BitOr, Shl, Shr};
+use core::ops::{BitAnd, BitOr, Shl, Shr};
 
 impl Limb {
     #[inline(always)]
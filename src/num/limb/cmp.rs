@@ -1,5 +1,5 @@
 use crate::num::limb::Limb;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 impl PartialEq for Limb {
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
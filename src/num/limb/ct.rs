@@ -0,0 +1,78 @@
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+
+impl Limb {
+    /// choice 为真时返回 b，否则返回 a
+    #[inline(always)]
+    pub fn ct_select(a: Self, b: Self, choice: Choice) -> Self {
+        Self(a.0 ^ ((a.0 ^ b.0) & choice.mask()))
+    }
+
+    /// choice 为真时交换 a, b
+    #[inline(always)]
+    pub fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let t = (a.0 ^ b.0) & choice.mask();
+        a.0 ^= t;
+        b.0 ^= t;
+    }
+
+    #[inline(always)]
+    pub fn ct_is_nonzero(self) -> Choice {
+        // x | -x 的最高位为 1 当且仅当 x != 0
+        Choice::from_bit((self.0 | self.0.wrapping_neg()) >> (Self::BITS - 1))
+    }
+
+    #[inline(always)]
+    pub fn ct_is_zero(self) -> Choice {
+        !self.ct_is_nonzero()
+    }
+
+    #[inline(always)]
+    pub fn ct_eq(self, rhs: Self) -> Choice {
+        Limb(self.0 ^ rhs.0).ct_is_zero()
+    }
+
+    #[inline(always)]
+    pub fn ct_lt(self, rhs: Self) -> Choice {
+        let (_, borrow) = self.sbb(rhs, Limb::ZERO);
+        Choice::from_mask(borrow.0)
+    }
+
+    #[inline(always)]
+    pub fn ct_gt(self, rhs: Self) -> Choice {
+        rhs.ct_lt(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::choice::Choice;
+    use crate::num::limb::Limb;
+
+    #[test]
+    fn test_ct_cmp() {
+        let values = [0, 1, 2, u64::MAX - 1, u64::MAX, 1 << 63];
+        for a in values {
+            for b in values {
+                let (la, lb) = (Limb(a), Limb(b));
+                assert_eq!(a == b, la.ct_eq(lb).to_bool_vartime());
+                assert_eq!(a < b, la.ct_lt(lb).to_bool_vartime());
+                assert_eq!(a > b, la.ct_gt(lb).to_bool_vartime());
+            }
+            assert_eq!(a == 0, Limb(a).ct_is_zero().to_bool_vartime());
+        }
+    }
+
+    #[test]
+    fn test_ct_select_swap() {
+        let (a, b) = (Limb(3), Limb(5));
+        assert_eq!(a, Limb::ct_select(a, b, Choice::FALSE));
+        assert_eq!(b, Limb::ct_select(a, b, Choice::TRUE));
+
+        let (mut x, mut y) = (a, b);
+        Limb::ct_swap(&mut x, &mut y, Choice::FALSE);
+        assert_eq!((a, b), (x, y));
+        Limb::ct_swap(&mut x, &mut y, Choice::TRUE);
+        assert_eq!((b, a), (x, y));
+    }
+}
@@ -1,7 +1,11 @@
+use alloc::format;
+use alloc::string::String;
+
 use crate::num::limb::Limb;
+
 impl Limb {
     #[inline(always)]
-    pub(crate) fn to_binary_string(self, trim_leading_zero: bool) -> String {
+    pub fn to_binary_string(self, trim_leading_zero: bool) -> String {
         if trim_leading_zero {
             format!("{:b}", self.0)
         } else {
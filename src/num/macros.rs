@@ -0,0 +1,31 @@
+/// 按值与按引用的组合统一转发到 `&T op &T`，`T` 需先实现按引用的运算
+///
+/// ```ignore
+/// forward_binop!([const LIMBS: usize] ModInt<LIMBS>; Add, add; Sub, sub);
+/// ```
+macro_rules! forward_binop {
+    ($generics:tt $ty:ty; $($trait:ident, $method:ident);+) => {
+        $(
+            forward_binop!(@impl $generics $ty, $trait, $method);
+        )+
+    };
+    (@impl [$($generics:tt)*] $ty:ty, $trait:ident, $method:ident) => {
+        impl<$($generics)*> $trait for $ty {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<$($generics)*> $trait<&$ty> for $ty {
+            type Output = Self;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+    };
+}
+
+pub(crate) use forward_binop;
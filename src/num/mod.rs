@@ -1,18 +1,51 @@
 mod add;
+mod barrett;
+#[cfg(feature = "alloc")]
+mod big_uint;
 mod bit_ops;
+mod choice;
 mod cmp;
+mod ct;
 mod div;
+mod encoding;
+mod error;
 mod fmt;
 mod from;
 mod gcd;
+mod int;
 mod inverse;
+mod jacobi;
 mod limb;
+mod macros;
+mod mod_add;
+mod mod_int;
 mod mod_mul;
+mod mod_sub;
 mod monty;
 mod mul;
+mod parse;
 mod pow_mod;
+mod prime;
 mod rand;
 mod rem;
+mod sqrt;
 mod sub;
 mod uint;
 mod wide;
+
+pub use barrett::BarrettParams;
+#[cfg(feature = "alloc")]
+pub use big_uint::BigUint;
+pub use choice::Choice;
+pub use error::{OverflowError, ParseError};
+pub use gcd::{binary_gcd, gcd};
+pub use int::{Int, I1024, I128, I2048, I256, I4096, I512, I64, I8192};
+pub use jacobi::jacobi;
+pub use limb::Limb;
+pub use mod_int::{ModInt, Modulus};
+pub(crate) use monty::TrimmedMontyParams;
+pub use monty::{ConstMontyForm, ConstMontyParams, MontyForm, MontyParams};
+pub use pow_mod::Exponent;
+pub use prime::PrimeOptions;
+pub use uint::{Uint, U1024, U128, U2048, U256, U4096, U512, U64, U8192};
+pub use wide::Wide;
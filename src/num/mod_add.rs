@@ -0,0 +1,38 @@
+use crate::num::limb::Limb;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    #[inline(always)]
+    /// (self + rhs) mod m，要求 self < m 且 rhs < m
+    pub fn add_mod(&self, rhs: &Self, m: &Self) -> Self {
+        let (sum, carry) = self.adc(rhs, Limb::ZERO);
+        sum.ct_sub_if_ge_with_carry(carry, m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::uint::U128;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_add_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let m: u128 = rng.gen_range(1..=u128::MAX);
+            let a: u128 = rng.gen_range(0..m);
+            let b: u128 = rng.gen_range(0..m);
+            let expect = if a >= m - b { a - (m - b) } else { a + b };
+
+            let actual = U128::from_u128(a).add_mod(&U128::from_u128(b), &U128::from_u128(m));
+            assert_eq!(
+                U128::from_u128(expect),
+                actual,
+                "a: {} b: {} m: {}",
+                a,
+                b,
+                m
+            );
+        }
+    }
+}
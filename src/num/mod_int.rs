@@ -0,0 +1,303 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::num::barrett::BarrettParams;
+use crate::num::macros::forward_binop;
+use crate::num::monty::{MontyForm, MontyParams};
+use crate::num::pow_mod::Exponent;
+use crate::num::uint::Uint;
+
+/// 模数上下文: 奇数模数使用蒙哥马利表示，其余 (偶数) 模数使用 Barrett 约减
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Modulus<const LIMBS: usize> {
+    params: Params<LIMBS>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Params<const LIMBS: usize> {
+    Monty(MontyParams<LIMBS>),
+    Barrett(BarrettParams<LIMBS>),
+}
+
+impl<const LIMBS: usize> Modulus<LIMBS> {
+    /// Returns `None` if `n` is zero.
+    pub fn new(n: &Uint<LIMBS>) -> Option<Self> {
+        if n.is_zero() {
+            return None;
+        }
+        let params = match MontyParams::init(n) {
+            Some(params) if *n > Uint::ONE => Params::Monty(params),
+            _ => Params::Barrett(BarrettParams::init(n)?),
+        };
+        Some(Self { params })
+    }
+
+    #[inline(always)]
+    pub fn get(&self) -> &Uint<LIMBS> {
+        match &self.params {
+            Params::Monty(params) => params.modulus(),
+            Params::Barrett(params) => params.modulus(),
+        }
+    }
+}
+
+/// 模 n 剩余类，同一运算的两个操作数必须属于同一个模数
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ModInt<const LIMBS: usize> {
+    residue: Residue<LIMBS>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Residue<const LIMBS: usize> {
+    Monty(MontyForm<LIMBS>),
+    /// 标准形式的值，总小于模数
+    Barrett(Uint<LIMBS>, BarrettParams<LIMBS>),
+}
+
+impl<const LIMBS: usize> ModInt<LIMBS> {
+    /// x mod n
+    pub fn new(x: &Uint<LIMBS>, modulus: &Modulus<LIMBS>) -> Self {
+        let residue = match modulus.params {
+            Params::Monty(params) => Residue::Monty(params.to_monty_form(x)),
+            Params::Barrett(params) => Residue::Barrett(*x % params.modulus(), params),
+        };
+        Self { residue }
+    }
+
+    #[inline(always)]
+    pub fn zero(modulus: &Modulus<LIMBS>) -> Self {
+        Self::new(&Uint::ZERO, modulus)
+    }
+
+    #[inline(always)]
+    pub fn one(modulus: &Modulus<LIMBS>) -> Self {
+        match modulus.params {
+            Params::Monty(params) => Self {
+                residue: Residue::Monty(MontyForm::one(&params)),
+            },
+            Params::Barrett(_) => Self::new(&Uint::ONE, modulus),
+        }
+    }
+
+    pub fn modulus(&self) -> Modulus<LIMBS> {
+        let params = match self.residue {
+            Residue::Monty(form) => Params::Monty(*form.params()),
+            Residue::Barrett(_, params) => Params::Barrett(params),
+        };
+        Modulus { params }
+    }
+
+    /// 标准形式的值，范围为 [0, n)
+    pub fn retrieve(&self) -> Uint<LIMBS> {
+        match self.residue {
+            Residue::Monty(form) => form.normalize(),
+            Residue::Barrett(x, _) => x,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        match self.residue {
+            Residue::Monty(form) => form.is_zero(),
+            Residue::Barrett(x, _) => x.is_zero(),
+        }
+    }
+
+    pub fn square(&self) -> Self {
+        let residue = match self.residue {
+            Residue::Monty(form) => Residue::Monty(form.square()),
+            Residue::Barrett(x, params) => Residue::Barrett(params.square_mod(&x), params),
+        };
+        Self { residue }
+    }
+
+    pub fn pow<X: Exponent + ?Sized>(&self, exp: &X) -> Self {
+        let residue = match self.residue {
+            Residue::Monty(form) => Residue::Monty(form.pow(exp)),
+            Residue::Barrett(x, params) => Residue::Barrett(params.pow_mod(&x, exp), params),
+        };
+        Self { residue }
+    }
+
+    /// 乘法逆元，与模数不互素时返回 `None`
+    pub fn inv(&self) -> Option<Self> {
+        let modulus = self.modulus();
+        let inv = self.retrieve().mod_inv(modulus.get())?;
+        Some(Self::new(&inv, &modulus))
+    }
+
+    /// 逐项组合两个同模数的剩余
+    #[inline(always)]
+    fn zip(
+        &self,
+        rhs: &Self,
+        monty: impl FnOnce(&MontyForm<LIMBS>, &MontyForm<LIMBS>) -> MontyForm<LIMBS>,
+        barrett: impl FnOnce(&Uint<LIMBS>, &Uint<LIMBS>, &BarrettParams<LIMBS>) -> Uint<LIMBS>,
+    ) -> Self {
+        let residue = match (&self.residue, &rhs.residue) {
+            (Residue::Monty(a), Residue::Monty(b)) if a.params() == b.params() => {
+                Residue::Monty(monty(a, b))
+            }
+            (Residue::Barrett(a, params), Residue::Barrett(b, rhs_params))
+                if params == rhs_params =>
+            {
+                Residue::Barrett(barrett(a, b, params), *params)
+            }
+            _ => panic!("operands have different moduli"),
+        };
+        Self { residue }
+    }
+}
+
+impl<const LIMBS: usize> Add<&ModInt<LIMBS>> for &ModInt<LIMBS> {
+    type Output = ModInt<LIMBS>;
+
+    fn add(self, rhs: &ModInt<LIMBS>) -> Self::Output {
+        self.zip(rhs, MontyForm::add, |a, b, params| {
+            a.add_mod(b, params.modulus())
+        })
+    }
+}
+
+impl<const LIMBS: usize> Sub<&ModInt<LIMBS>> for &ModInt<LIMBS> {
+    type Output = ModInt<LIMBS>;
+
+    fn sub(self, rhs: &ModInt<LIMBS>) -> Self::Output {
+        self.zip(rhs, MontyForm::sub, |a, b, params| {
+            a.sub_mod(b, params.modulus())
+        })
+    }
+}
+
+impl<const LIMBS: usize> Mul<&ModInt<LIMBS>> for &ModInt<LIMBS> {
+    type Output = ModInt<LIMBS>;
+
+    fn mul(self, rhs: &ModInt<LIMBS>) -> Self::Output {
+        self.zip(rhs, MontyForm::mul, |a, b, params| params.mul_mod(a, b))
+    }
+}
+
+impl<const LIMBS: usize> Div<&ModInt<LIMBS>> for &ModInt<LIMBS> {
+    type Output = ModInt<LIMBS>;
+
+    /// Panics if `rhs` is not invertible.
+    fn div(self, rhs: &ModInt<LIMBS>) -> Self::Output {
+        let inv = rhs
+            .inv()
+            .expect("attempt to divide by a non-invertible residue");
+        Mul::mul(self, &inv)
+    }
+}
+
+impl<const LIMBS: usize> Neg for &ModInt<LIMBS> {
+    type Output = ModInt<LIMBS>;
+
+    fn neg(self) -> Self::Output {
+        let residue = match self.residue {
+            Residue::Monty(form) => Residue::Monty(form.neg()),
+            Residue::Barrett(x, params) => {
+                Residue::Barrett(Uint::ZERO.sub_mod(&x, params.modulus()), params)
+            }
+        };
+        ModInt { residue }
+    }
+}
+
+impl<const LIMBS: usize> Neg for ModInt<LIMBS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+forward_binop!([const LIMBS: usize] ModInt<LIMBS>; Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl<const LIMBS: usize> fmt::Display for ModInt<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.retrieve(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::mod_int::{ModInt, Modulus};
+    use crate::num::uint::{U128, U256};
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+        for i in 0..200 {
+            // 奇偶模数交替，分别走蒙哥马利与 Barrett
+            let n = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..200));
+            let n = if i % 2 == 0 {
+                n.bitor(&U256::ONE)
+            } else {
+                n.wrapping_shl1().bitor(&U256::from_u64(2))
+            };
+            let modulus = Modulus::new(&n).unwrap();
+            let (a, b) = (U256::rand(&mut rng), U256::rand(&mut rng));
+            let (x, y) = (ModInt::new(&a, &modulus), ModInt::new(&b, &modulus));
+            let (a, b) = (a % n, b % n);
+
+            assert_eq!(a, x.retrieve());
+            assert_eq!(a.add_mod(&b, &n), (x + y).retrieve());
+            assert_eq!(a.sub_mod(&b, &n), (x - y).retrieve());
+            assert_eq!(a.mul_mod(&b, &n), (x * y).retrieve());
+            assert_eq!(U256::ZERO.sub_mod(&a, &n), (-x).retrieve());
+            assert_eq!(a.mul_mod(&a, &n), x.square().retrieve());
+            assert_eq!(a.mod_exp(&b, &n), x.pow(&b).retrieve());
+            assert_eq!(x, x + ModInt::zero(&modulus));
+            assert_eq!(x, x * ModInt::one(&modulus));
+        }
+    }
+
+    #[test]
+    fn test_div() {
+        // 2^127 - 1 为素数
+        let p = U128::from_u128(u128::MAX >> 1);
+        let modulus = Modulus::new(&p).unwrap();
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let x = ModInt::new(&U128::rand(&mut rng), &modulus);
+            let y = ModInt::new(&U128::rand(&mut rng), &modulus);
+            if y.is_zero() {
+                continue;
+            }
+            assert_eq!(x, (x / y) * y);
+        }
+
+        // 偶数模数下偶数不可逆
+        let modulus = Modulus::new(&U128::from_u64(100)).unwrap();
+        assert!(ModInt::new(&U128::from_u64(4), &modulus).inv().is_none());
+        let x = ModInt::new(&U128::from_u64(3), &modulus);
+        assert_eq!(U128::from_u64(67), x.inv().unwrap().retrieve());
+    }
+
+    #[test]
+    fn test_zero_modulus() {
+        assert!(Modulus::new(&U128::ZERO).is_none());
+        assert!(Modulus::new(&U256::ZERO).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "operands have different moduli")]
+    fn test_different_moduli() {
+        let m1 = Modulus::new(&U128::from_u64(7)).unwrap();
+        let m2 = Modulus::new(&U128::from_u64(11)).unwrap();
+        let _ = ModInt::new(&U128::ONE, &m1) + ModInt::new(&U128::ONE, &m2);
+    }
+
+    #[test]
+    fn test_display() {
+        let modulus = Modulus::new(&U128::from_u64(1000)).unwrap();
+        let x = ModInt::new(&U128::from_u64(123456), &modulus);
+        assert_eq!("456", x.to_string());
+        // 模 1 时 1 = 0
+        let one = ModInt::one(&Modulus::new(&U128::ONE).unwrap());
+        assert!(one.is_zero());
+        assert_eq!("0", one.to_string());
+    }
+}
@@ -2,9 +2,14 @@ use crate::num::uint::Uint;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) fn mul_mod(&self, rhs: &Self, m: &Self) -> Self {
+    pub fn mul_mod(&self, rhs: &Self, m: &Self) -> Self {
         self.split_mul(rhs).rem(m)
     }
+
+    #[inline(always)]
+    pub fn square_mod(&self, m: &Self) -> Self {
+        self.square_wide().rem(m)
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +38,14 @@ mod test {
         let expect = U128::from(202096250777435246437358086672401778403u128);
         assert_eq!(expect, rs)
     }
+
+    #[test]
+    fn test_square_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a = U128::rand(&mut rng);
+            let m = U128::rand(&mut rng).bitor(&U128::ONE);
+            assert_eq!(a.mul_mod(&a, &m), a.square_mod(&m));
+        }
+    }
 }
@@ -0,0 +1,41 @@
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    #[inline(always)]
+    /// (self - rhs) mod m，要求 self < m 且 rhs < m
+    pub fn sub_mod(&self, rhs: &Self, m: &Self) -> Self {
+        let (diff, borrow) = self.sbb(rhs, Limb::ZERO);
+        // 借位时加回 m
+        let m = Uint::ct_select(&Uint::ZERO, m, Choice::from_mask(borrow.0));
+        diff.wrapping_add(&m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::uint::U128;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_sub_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let m: u128 = rng.gen_range(1..=u128::MAX);
+            let a: u128 = rng.gen_range(0..m);
+            let b: u128 = rng.gen_range(0..m);
+            let expect = if a >= b { a - b } else { m - (b - a) };
+
+            let actual = U128::from_u128(a).sub_mod(&U128::from_u128(b), &U128::from_u128(m));
+            assert_eq!(
+                U128::from_u128(expect),
+                actual,
+                "a: {} b: {} m: {}",
+                a,
+                b,
+                m
+            );
+        }
+    }
+}
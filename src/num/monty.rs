@@ -1,5 +1,14 @@
-use std::ops::Rem;
+mod constant;
+mod ladder;
+mod pow;
+mod trimmed;
 
+pub use constant::{ConstMontyForm, ConstMontyParams};
+pub(crate) use trimmed::TrimmedMontyParams;
+
+use core::ops::Rem;
+
+use crate::num::choice::Choice;
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
 use crate::num::wide::Wide;
@@ -9,70 +18,64 @@ pub struct MontyParams<const LIMBS: usize> {
     n: Uint<LIMBS>,
     r: Uint<LIMBS>,
     r2: Uint<LIMBS>,
-    neg_inv_n: Uint<LIMBS>,
+    /// -n^{-1} mod 2^64，逐 limb 约减只需要最低的一个 limb
+    neg_inv_n0: Limb,
 }
 
 impl<const LIMBS: usize> MontyParams<LIMBS> {
     #[inline(always)]
     pub fn init(n: &Uint<LIMBS>) -> Option<Self> {
         let n = *n;
+        // n 为偶数 (含 0) 时不存在 -n^{-1} mod 2^64，须在取模之前返回
+        let neg_inv_n0 = Limb(inv_limb(n.limbs[0])?.0.wrapping_neg());
 
         // 2^k mod p = 2^k - 1 + 1 mod p = 2^k - 1 mod p + 1 mod p = Uint::MAX mod p + 1
-        let r = Uint::MAX.rem(&n).wrapping_add(&Uint::ONE);
-        let r2 = r.split_mul(&r).rem(&n);
-        let inv_n = n.mod_inv_2k(Uint::<LIMBS>::BITS as u32)?;
-        let neg_inv_n = Wide::from((Uint::ZERO, Uint::ONE))
-            .sub(&Wide::from((inv_n, Uint::ZERO)))
-            .low;
+        // n = 1 时 Uint::MAX mod n + 1 = n，需再约减一次
+        let r = Uint::MAX.rem(&n).wrapping_add(&Uint::ONE).ct_sub_if_ge(&n);
+        let r2 = r.square_wide().rem(&n);
         Some(Self {
             n,
             r,
             r2,
-            neg_inv_n,
+            neg_inv_n0,
         })
     }
 
+    #[inline(always)]
+    pub fn modulus(&self) -> &Uint<LIMBS> {
+        &self.n
+    }
+
+    /// 蒙哥马利乘法 a * b * r^{-1} mod n (CIOS)
+    #[inline(always)]
+    pub(crate) fn mul(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let mut t = [Limb::ZERO; LIMBS];
+        let t_hi = cios_mul(&a.limbs, &b.limbs, &self.n.limbs, self.neg_inv_n0, &mut t);
+
+        // t < 2n
+        Uint::new(t).ct_sub_if_ge_with_carry(t_hi, &self.n)
+    }
+
+    /// 蒙哥马利平方 a^2 * r^{-1} mod n
+    #[inline(always)]
+    pub(crate) fn square(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduction_wide(&a.square_wide())
+    }
+
+    /// 蒙哥马利约减 t * r^{-1} mod n
     #[inline(always)]
     pub fn reduction(&self, t: &Uint<LIMBS>) -> Uint<LIMBS> {
-        let m = t.split_mul(&self.neg_inv_n).low;
-        let ret = m.split_mul(&self.n).add(&Wide::from((*t, Uint::ZERO))).high;
-        if ret >= self.n {
-            ret - &self.n
-        } else {
-            ret
-        }
+        self.reduction_wide(&Wide::from((*t, Uint::ZERO)))
     }
 
+    /// 蒙哥马利约减 t * r^{-1} mod n，要求 t < n * r
     #[inline(always)]
     pub fn reduction_wide(&self, t: &Wide<LIMBS>) -> Uint<LIMBS> {
-        // m = (t * neg_inv_n) % r
-        let tm = t.mul(&Wide::from((self.neg_inv_n, Uint::ZERO)));
-        let mut m = Uint::ZERO;
-        m.limbs.copy_from_slice(&tm[0..LIMBS]);
-
-        // mn + t
-        let mn = m.split_mul(&self.n).to_limbs();
-        let mut mn_t = vec![Limb::ZERO; LIMBS * 4];
-        let t_rhs = t.to_limbs();
-        let mut carry = Limb::ZERO;
-        for i in 0..LIMBS * 2 {
-            let (w, c) = mn[i].adc(t_rhs[i], carry);
-            mn_t[i] = w;
-            carry = c;
-        }
-        mn_t[LIMBS * 2] = carry;
-
-        // (mn + t) / r
-        let mut ret = Wide::ZERO;
-        ret.low.limbs.copy_from_slice(&mn_t[LIMBS..2 * LIMBS]);
-        ret.high.limbs.copy_from_slice(&mn_t[LIMBS * 2..3 * LIMBS]);
+        let mut buf = [t.low.limbs, t.high.limbs];
+        let hi_carry = redc(buf.as_flattened_mut(), &self.n.limbs, self.neg_inv_n0);
 
-        let n_wide = Wide::from((self.n, Uint::ZERO));
-        if ret >= n_wide {
-            ret.sub(&n_wide).low
-        } else {
-            ret.low
-        }
+        // (t + mn) / r < 2n，最高位仅为进位
+        Uint::new(buf[1]).ct_sub_if_ge_with_carry(hi_carry, &self.n)
     }
 
     /// 映射到蒙哥马利空间
@@ -97,6 +100,86 @@ impl<const LIMBS: usize> MontyParams<LIMBS> {
     }
 }
 
+/// CIOS 核心: t = a * b * 2^{-64k}，k = n.len()，a、b 只取前 k 个 limb
+///
+/// 每处理 b 的一个 limb，先累加 a * b_i，再加上 m * n 使最低 limb 归零并整体右移一个 limb，
+/// 中间结果始终只有 k + 2 个 limb。结果写入 t[..k]，返回第 k 个 limb；
+/// 输入均小于 n 时结果小于 2n，由调用方做最终减法
+#[inline(always)]
+fn cios_mul(a: &[Limb], b: &[Limb], n: &[Limb], neg_inv_n0: Limb, t: &mut [Limb]) -> Limb {
+    let k = n.len();
+    let t = &mut t[..k];
+    t.fill(Limb::ZERO);
+    let mut t_hi = Limb::ZERO;
+
+    for &b_i in &b[..k] {
+        // t += a * b_i
+        let mut carry = Limb::ZERO;
+        for (t_j, &a_j) in t.iter_mut().zip(&a[..k]) {
+            let (w, c) = t_j.mac(a_j, b_i, carry);
+            *t_j = w;
+            carry = c;
+        }
+        let (t_s, t_s1) = t_hi.adc(carry, Limb::ZERO);
+
+        // t = (t + m * n) / 2^64
+        let m = Limb(t[0].0.wrapping_mul(neg_inv_n0.0));
+        let (_, mut carry) = t[0].mac(m, n[0], Limb::ZERO);
+        for j in 1..k {
+            let (w, c) = t[j].mac(m, n[j], carry);
+            t[j - 1] = w;
+            carry = c;
+        }
+        let (w, c) = t_s.adc(carry, Limb::ZERO);
+        t[k - 1] = w;
+        t_hi = Limb(t_s1.0 + c.0);
+    }
+
+    t_hi
+}
+
+/// 逐 limb 的蒙哥马利约减核心: t * 2^{-64k}，k = n.len()，t 的长度为 2k
+///
+/// 每一步加上 m * n * 2^{64i} 使第 i 个 limb 归零，结果留在 t[k..2k]，返回最高位进位
+#[inline(always)]
+fn redc(t: &mut [Limb], n: &[Limb], neg_inv_n0: Limb) -> Limb {
+    let k = n.len();
+    let mut hi_carry = Limb::ZERO;
+
+    for i in 0..k {
+        let m = Limb(t[i].0.wrapping_mul(neg_inv_n0.0));
+        let mut carry = Limb::ZERO;
+        for j in 0..k {
+            let (w, c) = t[i + j].mac(m, n[j], carry);
+            t[i + j] = w;
+            carry = c;
+        }
+        let (w, c) = t[i + k].adc(carry, hi_carry);
+        t[i + k] = w;
+        hi_carry = c;
+    }
+
+    hi_carry
+}
+
+/// x^{-1} mod 2^64，x 为偶数时不存在
+///
+/// 牛顿迭代 y = y * (2 - x * y)，每次迭代有效位数翻倍，x 本身即为 3 位精度的初值
+#[inline(always)]
+const fn inv_limb(x: Limb) -> Option<Limb> {
+    if x.0 & 1 == 0 {
+        return None;
+    }
+    let x = x.0;
+    let mut y = x;
+    let mut i = 0;
+    while i < 5 {
+        y = y.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(y)));
+        i += 1;
+    }
+    Some(Limb(y))
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct MontyForm<const LIMBS: usize> {
     form: Uint<LIMBS>,
@@ -104,10 +187,86 @@ pub struct MontyForm<const LIMBS: usize> {
 }
 
 impl<const LIMBS: usize> MontyForm<LIMBS> {
+    /// 1 的蒙哥马利形式，即 r mod n
+    #[inline(always)]
+    pub fn one(params: &MontyParams<LIMBS>) -> Self {
+        Self {
+            form: params.r,
+            params: *params,
+        }
+    }
+
+    #[inline(always)]
+    pub fn zero(params: &MontyParams<LIMBS>) -> Self {
+        Self {
+            form: Uint::ZERO,
+            params: *params,
+        }
+    }
+
+    #[inline(always)]
+    pub fn params(&self) -> &MontyParams<LIMBS> {
+        &self.params
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.form.is_zero()
+    }
+
     #[inline(always)]
     pub fn mul(&self, rhs: &Self) -> Self {
         Self {
-            form: self.params.reduction_wide(&self.form.split_mul(&rhs.form)),
+            form: self.params.mul(&self.form, &rhs.form),
+            params: self.params,
+        }
+    }
+
+    #[inline(always)]
+    pub fn square(&self) -> Self {
+        Self {
+            form: self.params.square(&self.form),
+            params: self.params,
+        }
+    }
+
+    /// 蒙哥马利形式对加减法是线性的: aR + bR = (a + b)R
+    #[inline(always)]
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self {
+            form: self.form.add_mod(&rhs.form, &self.params.n),
+            params: self.params,
+        }
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self {
+            form: self.form.sub_mod(&rhs.form, &self.params.n),
+            params: self.params,
+        }
+    }
+
+    #[inline(always)]
+    pub fn neg(&self) -> Self {
+        Self::zero(&self.params).sub(self)
+    }
+
+    #[inline(always)]
+    pub fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// self / 2 mod n，n 为奇数: 偶数直接右移，奇数先加 n 再右移
+    #[inline(always)]
+    pub fn div_by_2(&self) -> Self {
+        let is_odd = Choice::from_bit(self.form.limbs[0].0 & 1);
+        let addend = Uint::ct_select(&Uint::ZERO, &self.params.n, is_odd);
+        let (sum, carry) = self.form.adc(&addend, Limb::ZERO);
+        let top = Uint::from_u64(carry.0).wrapping_shl(Uint::<LIMBS>::BITS as u32 - 1);
+        let form = sum.wrapping_shr1().bitor(&top);
+        Self {
+            form,
             params: self.params,
         }
     }
@@ -121,12 +280,11 @@ impl<const LIMBS: usize> MontyForm<LIMBS> {
 
 #[cfg(test)]
 mod test {
-    use std::u128;
-
     use rand::{thread_rng, Rng};
 
-    use crate::num::monty::MontyParams;
-    use crate::num::uint::U128;
+    use crate::num::limb::Limb;
+    use crate::num::monty::{inv_limb, MontyForm, MontyParams};
+    use crate::num::uint::{U128, U256};
 
     #[test]
     fn test_mod_mul() {
@@ -164,6 +322,82 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_inv_limb() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let x = rng.gen::<u64>() | 1;
+            let y = inv_limb(Limb(x)).unwrap();
+            assert_eq!(1, x.wrapping_mul(y.0));
+        }
+        assert!(inv_limb(Limb(2)).is_none());
+    }
+
+    #[test]
+    fn test_init_rejects_even() {
+        assert!(MontyParams::init(&U128::ZERO).is_none());
+        assert!(MontyParams::init(&U128::from_u64(2)).is_none());
+    }
+
+    #[test]
+    fn test_cios_matches_reduction() {
+        let mut rng = thread_rng();
+        for i in 0..1000 {
+            // 包含最高 limb 为全 1 的模数，覆盖中间结果的进位
+            let mut m = U256::rand(&mut rng).bitor(&U256::ONE);
+            if i % 2 == 0 {
+                m.limbs[3] = Limb::MAX;
+            }
+            let params = MontyParams::init(&m).unwrap();
+            let a = U256::rand_mod(&mut rng, &m);
+            let b = U256::rand_mod(&mut rng, &m);
+
+            let expect = params.reduction_wide(&a.split_mul(&b));
+            assert_eq!(expect, params.mul(&a, &b), "a: {} b: {} m: {}", a, b, m);
+            assert_eq!(
+                a.mul_mod(&b, &m),
+                params
+                    .to_monty_form(&a)
+                    .mul(&params.to_monty_form(&b))
+                    .normalize()
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let m = U128::from_u128(rng.gen::<u128>() | 1);
+            let a = U128::rand_mod(&mut rng, &m);
+            let b = U128::rand_mod(&mut rng, &m);
+            let params = MontyParams::init(&m).unwrap();
+            let ma = params.to_monty_form(&a);
+            let mb = params.to_monty_form(&b);
+
+            assert_eq!(a.add_mod(&b, &m), ma.add(&mb).normalize());
+            assert_eq!(a.sub_mod(&b, &m), ma.sub(&mb).normalize());
+            assert_eq!(U128::ZERO.sub_mod(&a, &m), ma.neg().normalize());
+            assert_eq!(a.add_mod(&a, &m), ma.double().normalize());
+            assert_eq!(a, ma.double().div_by_2().normalize());
+            assert_eq!(a.mul_mod(&a, &m), ma.square().normalize());
+        }
+    }
+
+    #[test]
+    fn test_one_zero() {
+        let m = U128::from_u128(287215270712012985982119861826231487661);
+        let params = MontyParams::init(&m).unwrap();
+        let a = params.to_monty_form(&U128::from_u64(12345));
+
+        assert_eq!(U128::ONE, MontyForm::one(&params).normalize());
+        assert_eq!(U128::ZERO, MontyForm::zero(&params).normalize());
+        assert_eq!(MontyForm::one(&params), params.to_monty_form(&U128::ONE));
+        assert_eq!(a, a.mul(&MontyForm::one(&params)));
+        assert_eq!(a, a.add(&MontyForm::zero(&params)));
+        assert!(a.sub(&a).is_zero());
+    }
+
     #[test]
     fn test_to_monty_form() {
         let mut rng = thread_rng();
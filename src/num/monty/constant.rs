@@ -0,0 +1,270 @@
+use core::marker::PhantomData;
+
+use crate::num::limb::Limb;
+use crate::num::monty::{inv_limb, MontyForm, MontyParams};
+use crate::num::pow_mod::Exponent;
+use crate::num::uint::Uint;
+
+/// (2 * x) mod n，要求 x < n，仅用于编译期计算
+const fn double_mod<const LIMBS: usize>(x: &Uint<LIMBS>, n: &Uint<LIMBS>) -> Uint<LIMBS> {
+    let mut double = [Limb::ZERO; LIMBS];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < LIMBS {
+        let limb = x.limbs[i].0;
+        double[i] = Limb((limb << 1) | carry);
+        carry = limb >> (Limb::BITS - 1);
+        i += 1;
+    }
+
+    let mut diff = [Limb::ZERO; LIMBS];
+    let mut borrow = Limb::ZERO;
+    let mut i = 0;
+    while i < LIMBS {
+        let (d, b) = double[i].sbb(n.limbs[i], borrow);
+        diff[i] = d;
+        borrow = b;
+        i += 1;
+    }
+
+    // 2x >= n 当且仅当移位产生进位或减法没有借位
+    if carry == 1 || borrow.0 == 0 {
+        Uint::new(diff)
+    } else {
+        Uint::new(double)
+    }
+}
+
+const fn is_one<const LIMBS: usize>(x: &Uint<LIMBS>) -> bool {
+    let mut i = 1;
+    while i < LIMBS {
+        if x.limbs[i].0 != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    x.limbs[0].0 == 1
+}
+
+impl<const LIMBS: usize> MontyParams<LIMBS> {
+    /// `init` 的 const 版本，用于在编译期声明固定模数
+    ///
+    /// r 与 r2 通过 2 * BITS 次模加倍得到，不依赖除法；n 为偶数时在编译期报错
+    pub const fn new_const(n: &Uint<LIMBS>) -> Self {
+        let neg_inv_n0 = match inv_limb(n.limbs[0]) {
+            Some(inv) => Limb(inv.0.wrapping_neg()),
+            None => panic!("modulus must be odd"),
+        };
+
+        let (r, r2) = if is_one(n) {
+            (Uint::ZERO, Uint::ZERO)
+        } else {
+            // r = 2^BITS mod n, r2 = 2^{2 * BITS} mod n
+            let mut r = Uint::ONE;
+            let mut i = 0;
+            while i < Uint::<LIMBS>::BITS {
+                r = double_mod(&r, n);
+                i += 1;
+            }
+            let mut r2 = r;
+            let mut i = 0;
+            while i < Uint::<LIMBS>::BITS {
+                r2 = double_mod(&r2, n);
+                i += 1;
+            }
+            (r, r2)
+        };
+
+        Self {
+            n: *n,
+            r,
+            r2,
+            neg_inv_n0,
+        }
+    }
+}
+
+/// 编译期常量模数，通常由 [`const_monty_params!`](crate::const_monty_params) 声明
+pub trait ConstMontyParams<const LIMBS: usize>:
+    Copy + Clone + core::fmt::Debug + PartialEq + Eq
+{
+    const PARAMS: MontyParams<LIMBS>;
+}
+
+/// 声明一个实现 [`ConstMontyParams`] 的零大小类型
+///
+/// ```
+/// use algebra::{const_monty_params, ConstMontyForm, U128};
+///
+/// // 2^127 - 1
+/// const_monty_params!(M127, U128, U128::from_u128(u128::MAX >> 1));
+///
+/// let x = ConstMontyForm::<M127, 2>::new(&U128::from_u64(3));
+/// assert_eq!(U128::from_u64(9), x.square().normalize());
+/// ```
+#[macro_export]
+macro_rules! const_monty_params {
+    ($vis:vis $name:ident, $uint:ty, $modulus:expr) => {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::ConstMontyParams<{ <$uint>::LIMBS }> for $name {
+            const PARAMS: $crate::MontyParams<{ <$uint>::LIMBS }> =
+                $crate::MontyParams::new_const(&$modulus);
+        }
+    };
+}
+
+/// 固定模数下的蒙哥马利形式，参数来自类型 P，值本身只有 LIMBS 个 limb
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConstMontyForm<P: ConstMontyParams<LIMBS>, const LIMBS: usize> {
+    form: Uint<LIMBS>,
+    params: PhantomData<P>,
+}
+
+impl<P: ConstMontyParams<LIMBS>, const LIMBS: usize> ConstMontyForm<P, LIMBS> {
+    pub const ZERO: Self = Self::from_form(Uint::ZERO);
+    pub const ONE: Self = Self::from_form(P::PARAMS.r);
+
+    #[inline(always)]
+    const fn from_form(form: Uint<LIMBS>) -> Self {
+        Self {
+            form,
+            params: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn to_monty_form(self) -> MontyForm<LIMBS> {
+        MontyForm {
+            form: self.form,
+            params: P::PARAMS,
+        }
+    }
+
+    /// 映射到蒙哥马利空间
+    #[inline(always)]
+    pub fn new(x: &Uint<LIMBS>) -> Self {
+        Self::from_form(P::PARAMS.to_monty_form(x).form)
+    }
+
+    #[inline(always)]
+    pub fn modulus() -> Uint<LIMBS> {
+        P::PARAMS.n
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.form.is_zero()
+    }
+
+    #[inline(always)]
+    pub fn mul(&self, rhs: &Self) -> Self {
+        Self::from_form(P::PARAMS.mul(&self.form, &rhs.form))
+    }
+
+    #[inline(always)]
+    pub fn square(&self) -> Self {
+        Self::from_form(P::PARAMS.square(&self.form))
+    }
+
+    #[inline(always)]
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self::from_form(self.form.add_mod(&rhs.form, &P::PARAMS.n))
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self::from_form(self.form.sub_mod(&rhs.form, &P::PARAMS.n))
+    }
+
+    #[inline(always)]
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    pub fn pow<X: Exponent + ?Sized>(&self, exp: &X) -> Self {
+        Self::from_form(self.to_monty_form().pow(exp).form)
+    }
+
+    /// 恒定时间指数运算，见 [`MontyForm::pow_ct`]
+    pub fn pow_ct<const EXP_LIMBS: usize>(&self, exp: &Uint<EXP_LIMBS>) -> Self {
+        Self::from_form(self.to_monty_form().pow_ct(exp).form)
+    }
+
+    /// 从蒙哥马利空间转换为标准形式
+    #[inline(always)]
+    pub fn normalize(&self) -> Uint<LIMBS> {
+        P::PARAMS.reduction(&self.form)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::monty::constant::ConstMontyForm;
+    use crate::num::monty::MontyParams;
+    use crate::num::uint::{U128, U256, U64};
+
+    // secp256k1 的基域素数 2^256 - 2^32 - 977
+    const P256K1: U256 = U256::from_words([
+        0xFFFF_FFFE_FFFF_FC2F,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+    ]);
+
+    crate::const_monty_params!(Secp256k1, U256, P256K1);
+    crate::const_monty_params!(Small, U64, U64::from_u64(1000000007));
+
+    type Fp = ConstMontyForm<Secp256k1, 4>;
+
+    #[test]
+    fn test_new_const_matches_init() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let n = U128::rand(&mut rng).bitor(&U128::ONE);
+            assert_eq!(MontyParams::init(&n).unwrap(), MontyParams::new_const(&n));
+        }
+        for n in [U128::ONE, U128::from_u64(3), U128::MAX] {
+            assert_eq!(MontyParams::init(&n).unwrap(), MontyParams::new_const(&n));
+        }
+        assert_eq!(
+            MontyParams::init(&P256K1).unwrap(),
+            MontyParams::new_const(&P256K1)
+        );
+    }
+
+    #[test]
+    fn test_const_form() {
+        // 不携带运行时参数
+        assert_eq!(size_of::<U256>(), size_of::<Fp>());
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = U256::rand_mod(&mut rng, &P256K1);
+            let b = U256::rand_mod(&mut rng, &P256K1);
+            let (x, y) = (Fp::new(&a), Fp::new(&b));
+
+            assert_eq!(a.mul_mod(&b, &P256K1), x.mul(&y).normalize());
+            assert_eq!(a.add_mod(&b, &P256K1), x.add(&y).normalize());
+            assert_eq!(a.sub_mod(&b, &P256K1), x.sub(&y).normalize());
+            assert_eq!(a.mul_mod(&a, &P256K1), x.square().normalize());
+            assert_eq!(U256::ZERO.sub_mod(&a, &P256K1), x.neg().normalize());
+            assert_eq!(a.mod_exp(&b, &P256K1), x.pow(&b).normalize());
+            assert_eq!(x.pow(&b), x.pow_ct(&b));
+        }
+
+        assert_eq!(U256::ONE, Fp::ONE.normalize());
+        assert!(Fp::ZERO.is_zero());
+        assert_eq!(P256K1, Fp::modulus());
+    }
+
+    #[test]
+    fn test_const_form_small() {
+        // 费马小定理
+        let x = ConstMontyForm::<Small, 1>::new(&U64::from_u64(123456789));
+        assert_eq!(ConstMontyForm::ONE, x.pow(&1000000006u64));
+    }
+}
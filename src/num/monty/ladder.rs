@@ -0,0 +1,165 @@
+use crate::num::choice::Choice;
+use crate::num::monty::MontyForm;
+use crate::num::uint::Uint;
+
+/// 蒙哥马利阶梯所需的运算
+///
+/// 阶梯本身只通过该 trait 访问操作数，测试中可替换为记录运算序列的实现
+pub(crate) trait LadderOps: Sized {
+    fn ladder_mul(&self, rhs: &Self) -> Self;
+
+    fn ladder_square(&self) -> Self;
+
+    /// choice 为真时交换 a, b；不得依赖 choice 分支
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice);
+}
+
+/// 蒙哥马利阶梯 base^{exp}，要求 exp < 2^{bits}
+///
+/// 对 exp 的低 `bits` 位逐位处理，每一位恰好执行一次条件交换、一次乘法与一次平方，
+/// 运算序列只取决于公开的 `bits`，与指数的取值无关
+pub(crate) fn ladder<T: LadderOps, const EXP_LIMBS: usize>(
+    one: T,
+    base: T,
+    exp: &Uint<EXP_LIMBS>,
+    bits: usize,
+) -> T {
+    debug_assert!(bits <= Uint::<EXP_LIMBS>::BITS);
+
+    // 不变量: r1 = r0 * base
+    let mut r0 = one;
+    let mut r1 = base;
+    let mut swapped = 0u64;
+
+    for i in (0..bits).rev() {
+        let bit = (exp.limbs[i / 64].0 >> (i % 64)) & 1;
+
+        // 位为 1 时交换两个寄存器，使后续运算统一为 r1 = r0 * r1, r0 = r0^2
+        T::conditional_swap(&mut r0, &mut r1, Choice::from_bit(swapped ^ bit));
+        swapped = bit;
+
+        r1 = r0.ladder_mul(&r1);
+        r0 = r0.ladder_square();
+    }
+    T::conditional_swap(&mut r0, &mut r1, Choice::from_bit(swapped));
+
+    r0
+}
+
+impl<const LIMBS: usize> LadderOps for MontyForm<LIMBS> {
+    #[inline(always)]
+    fn ladder_mul(&self, rhs: &Self) -> Self {
+        self.mul(rhs)
+    }
+
+    #[inline(always)]
+    fn ladder_square(&self) -> Self {
+        self.square()
+    }
+
+    #[inline(always)]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        Uint::ct_swap(&mut a.form, &mut b.form, choice);
+    }
+}
+
+impl<const LIMBS: usize> MontyForm<LIMBS> {
+    /// self^{exp}，蒙哥马利阶梯实现的恒定时间指数运算，适用于私钥等秘密指数
+    ///
+    /// 运算次数只取决于指数的类型宽度 `Uint::<EXP_LIMBS>::BITS`，与指数的取值无关
+    pub fn pow_ct<const EXP_LIMBS: usize>(&self, exp: &Uint<EXP_LIMBS>) -> Self {
+        ladder(Self::one(&self.params), *self, exp, Uint::<EXP_LIMBS>::BITS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use rand::{thread_rng, Rng};
+
+    use crate::num::choice::Choice;
+    use crate::num::monty::ladder::{ladder, LadderOps};
+    use crate::num::monty::MontyParams;
+    use crate::num::uint::{U128, U256, U64};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Op {
+        Mul,
+        Square,
+        Swap,
+    }
+
+    /// 只记录运算序列的操作数
+    #[derive(Clone)]
+    struct Recorder {
+        trace: Rc<RefCell<Vec<Op>>>,
+    }
+
+    impl LadderOps for Recorder {
+        fn ladder_mul(&self, _rhs: &Self) -> Self {
+            self.trace.borrow_mut().push(Op::Mul);
+            self.clone()
+        }
+
+        fn ladder_square(&self) -> Self {
+            self.trace.borrow_mut().push(Op::Square);
+            self.clone()
+        }
+
+        fn conditional_swap(a: &mut Self, _b: &mut Self, _choice: Choice) {
+            a.trace.borrow_mut().push(Op::Swap);
+        }
+    }
+
+    fn trace<const LIMBS: usize>(exp: &crate::num::uint::Uint<LIMBS>, bits: usize) -> Vec<Op> {
+        let recorder = Recorder {
+            trace: Rc::new(RefCell::new(Vec::new())),
+        };
+        ladder(recorder.clone(), recorder.clone(), exp, bits);
+        recorder.trace.take()
+    }
+
+    #[test]
+    fn test_trace_independent_of_exponent() {
+        let mut rng = thread_rng();
+        let expect = trace(&U128::ZERO, U128::BITS);
+        assert_eq!(3 * U128::BITS + 1, expect.len());
+
+        for exp in [U128::ONE, U128::MAX, U128::ONE.wrapping_shl(127)] {
+            assert_eq!(expect, trace(&exp, U128::BITS));
+        }
+        for _ in 0..100 {
+            assert_eq!(expect, trace(&U128::rand(&mut rng), U128::BITS));
+        }
+
+        // 限定位数时运算次数只取决于 bits
+        let expect = trace(&U128::ZERO, 70);
+        assert_eq!(3 * 70 + 1, expect.len());
+        assert_eq!(expect, trace(&U128::ONE.wrapping_shl(69), 70));
+    }
+
+    #[test]
+    fn test_pow_ct() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = U128::rand(&mut rng);
+            let m = U128::rand(&mut rng).bitor(&U128::ONE);
+            let e = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..256));
+            let params = MontyParams::init(&m).unwrap();
+            let ma = params.to_monty_form(&a);
+            assert_eq!(ma.pow(&e), ma.pow_ct(&e), "a: {} e: {} m: {}", a, e, m);
+        }
+    }
+
+    #[test]
+    fn test_pow_ct_small() {
+        let m = U128::from_u64(1000000007);
+        let params = MontyParams::init(&m).unwrap();
+        let a = params.to_monty_form(&U128::from_u64(123456789));
+        for e in 0..64u64 {
+            assert_eq!(a.pow(&e), a.pow_ct(&U64::from_u64(e)));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::num::monty::MontyForm;
+use crate::num::pow_mod::{window_size, Exponent, SlidingWindows, MAX_WINDOW};
+use crate::num::uint::Uint;
+
+/// 固定窗口指数运算的窗口宽度
+const FIXED_WINDOW: usize = 4;
+
+impl<const LIMBS: usize> MontyForm<LIMBS> {
+    /// self^{exp}，从左到右滑动窗口，全程保持在蒙哥马利空间
+    pub fn pow<X: Exponent + ?Sized>(&self, exp: &X) -> Self {
+        let params = &self.params;
+        let window = window_size(exp.bits());
+
+        // 奇数次幂表: x^1, x^3, ..., x^{2^w - 1}
+        let mut table = [Uint::ZERO; 1 << (MAX_WINDOW - 1)];
+        table[0] = self.form;
+        if window > 1 {
+            let square = params.square(&self.form);
+            for i in 1..(1 << (window - 1)) {
+                table[i] = params.mul(&table[i - 1], &square);
+            }
+        }
+
+        let mut result = params.r;
+        for (squarings, value) in SlidingWindows::new(exp, window) {
+            for _ in 0..squarings {
+                result = params.square(&result);
+            }
+            if let Some(value) = value {
+                result = params.mul(&result, &table[value >> 1]);
+            }
+        }
+
+        Self {
+            form: result,
+            params: *params,
+        }
+    }
+
+    /// self^{exp}，从左到右固定窗口: 每个窗口恰好做 w 次平方与一次乘法
+    pub fn pow_fixed_window<X: Exponent + ?Sized>(&self, exp: &X) -> Self {
+        let params = &self.params;
+        let bits = exp.bits();
+
+        // 全部幂次表: x^0, x^1, ..., x^{2^w - 1}
+        let mut table = [Uint::ZERO; 1 << FIXED_WINDOW];
+        table[0] = params.r;
+        for i in 1..(1 << FIXED_WINDOW) {
+            table[i] = params.mul(&table[i - 1], &self.form);
+        }
+
+        let mut result = params.r;
+        for k in (0..bits.div_ceil(FIXED_WINDOW)).rev() {
+            for _ in 0..FIXED_WINDOW {
+                result = params.square(&result);
+            }
+
+            let mut value = 0;
+            for j in (k * FIXED_WINDOW..(k + 1) * FIXED_WINDOW).rev() {
+                value = (value << 1) | (j < bits && exp.bit(j)) as usize;
+            }
+            result = params.mul(&result, &table[value]);
+        }
+
+        Self {
+            form: result,
+            params: *params,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::monty::{MontyForm, MontyParams};
+    use crate::num::uint::{U128, U256};
+
+    #[test]
+    fn test_pow() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = U128::from_u128(rng.gen());
+            let e: u32 = rng.gen_range(0..64);
+            let m = U128::from_u128(rng.gen::<u128>() | 1);
+            let params = MontyParams::init(&m).unwrap();
+            let ma = params.to_monty_form(&a);
+
+            let expect = (0..e).fold(U128::ONE, |acc, _| acc.mul_mod(&a, &m));
+            assert_eq!(expect, ma.pow(&e).normalize(), "a: {} e: {} m: {}", a, e, m);
+            assert_eq!(expect, ma.pow_fixed_window(&e).normalize());
+        }
+    }
+
+    #[test]
+    fn test_pow_wide_exponent() {
+        let mut rng = thread_rng();
+        for _ in 0..20 {
+            let a = U128::rand(&mut rng);
+            let e = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..256));
+            let m = U128::rand(&mut rng).bitor(&U128::ONE);
+            let params = MontyParams::init(&m).unwrap();
+            let ma = params.to_monty_form(&a);
+
+            let expect = ma.pow(&e);
+            assert_eq!(expect, ma.pow_fixed_window(&e));
+            // a^{e} * a = a^{e + 1}
+            let (e1, overflow) = e.overflowing_add(&U256::ONE);
+            if !overflow {
+                assert_eq!(expect.mul(&ma), ma.pow(&e1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_zero() {
+        let m = U128::from_u64(1000000007);
+        let params = MontyParams::init(&m).unwrap();
+        let a = params.to_monty_form(&U128::from_u64(5));
+        assert_eq!(MontyForm::one(&params), a.pow(&0u32));
+        assert_eq!(MontyForm::one(&params), a.pow_fixed_window(&U256::ZERO));
+    }
+}
@@ -0,0 +1,242 @@
+use core::ops::Rem;
+
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+use crate::num::monty::ladder::{ladder, LadderOps};
+use crate::num::monty::{cios_mul, inv_limb, redc};
+use crate::num::mul::karatsuba::{karatsuba_square, schoolbook_square, KARATSUBA_THRESHOLD};
+use crate::num::uint::Uint;
+
+/// 只按模数的有效 limb 数运算的蒙哥马利参数
+///
+/// 取 r = 2^{64k}，k 为 n 的 limb 数。RSA 的 CRT 分支中 p, q 只占 `Uint<LIMBS>` 的一半，
+/// 乘法与约减的开销随 k 而非 LIMBS 增长
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct TrimmedMontyParams<const LIMBS: usize> {
+    n: Uint<LIMBS>,
+    k: usize,
+    /// r mod n，即 1 的蒙哥马利形式
+    r: Uint<LIMBS>,
+    /// r^3 mod n，约减后再乘以它即映射到蒙哥马利空间
+    r3: Uint<LIMBS>,
+    neg_inv_n0: Limb,
+}
+
+impl<const LIMBS: usize> TrimmedMontyParams<LIMBS> {
+    /// n 为偶数 (含 0) 时返回 `None`
+    pub(crate) fn init(n: &Uint<LIMBS>) -> Option<Self> {
+        let n = *n;
+        let neg_inv_n0 = Limb(inv_limb(n.limbs[0])?.0.wrapping_neg());
+        let k = n.bits().div_ceil(Limb::BITS);
+
+        let r = if k == LIMBS {
+            Uint::MAX.rem(&n).wrapping_add(&Uint::ONE).ct_sub_if_ge(&n)
+        } else {
+            let mut r = Uint::ZERO;
+            r.limbs[k] = Limb::ONE;
+            r.rem(&n)
+        };
+        let mut params = Self {
+            n,
+            k,
+            r,
+            r3: Uint::ZERO,
+            neg_inv_n0,
+        };
+        let r2 = r.square_wide().rem(&n);
+        params.r3 = params.mul(&r2, &r2);
+        Some(params)
+    }
+
+    /// a * b * r^{-1} mod n，要求 a, b < n
+    #[inline(always)]
+    pub(crate) fn mul(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let mut t = [Limb::ZERO; LIMBS];
+        let t_hi = cios_mul(
+            &a.limbs,
+            &b.limbs,
+            &self.n.limbs[..self.k],
+            self.neg_inv_n0,
+            &mut t,
+        );
+        self.sub_if_ge(t, t_hi)
+    }
+
+    /// a^2 * r^{-1} mod n，要求 a < n
+    #[inline(always)]
+    pub(crate) fn square(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let k = self.k;
+        let mut buf = [[Limb::ZERO; LIMBS]; 2];
+        let t = &mut buf.as_flattened_mut()[..2 * k];
+
+        if k >= KARATSUBA_THRESHOLD {
+            let mut scratch = [[Limb::ZERO; LIMBS]; 8];
+            karatsuba_square(&a.limbs[..k], t, scratch.as_flattened_mut());
+        } else {
+            schoolbook_square(&a.limbs[..k], t);
+        }
+        self.finish_redc(&mut buf)
+    }
+
+    /// x * r^{-1} mod n，要求 x < n * r，过程中没有依赖 x 的分支
+    #[inline(always)]
+    pub(crate) fn reduce(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        // x < n * r <= 2^{128k}，第 2k 个 limb 起均为 0
+        let mut buf = [x.limbs, [Limb::ZERO; LIMBS]];
+        self.finish_redc(&mut buf)
+    }
+
+    /// 映射到蒙哥马利空间 x * r mod n，要求 x < n * r
+    #[inline(always)]
+    pub(crate) fn monty_form(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mul(&self.reduce(x), &self.r3)
+    }
+
+    /// 从蒙哥马利空间转换为标准形式
+    #[inline(always)]
+    pub(crate) fn normalize(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce(a)
+    }
+
+    /// base^{exp}，base 为蒙哥马利形式，要求 exp < 2^{bits}
+    ///
+    /// 蒙哥马利阶梯只处理 exp 的低 `bits` 位，运算次数只取决于公开的 `bits`
+    pub(crate) fn pow_ct<const EXP_LIMBS: usize>(
+        &self,
+        base: &Uint<LIMBS>,
+        exp: &Uint<EXP_LIMBS>,
+        bits: usize,
+    ) -> Uint<LIMBS> {
+        let one = TrimmedForm {
+            form: self.r,
+            params: self,
+        };
+        let base = TrimmedForm {
+            form: *base,
+            params: self,
+        };
+        ladder(one, base, exp, bits).form
+    }
+
+    /// 约减 buf 的前 2k 个 limb，(t + mn) / r < 2n 位于 [k, 2k)
+    #[inline(always)]
+    fn finish_redc(&self, buf: &mut [[Limb; LIMBS]; 2]) -> Uint<LIMBS> {
+        let k = self.k;
+        let t = buf.as_flattened_mut();
+        let hi_carry = redc(&mut t[..2 * k], &self.n.limbs[..k], self.neg_inv_n0);
+
+        let mut limbs = [Limb::ZERO; LIMBS];
+        limbs[..k].copy_from_slice(&t[k..2 * k]);
+        self.sub_if_ge(limbs, hi_carry)
+    }
+
+    /// 对 k 个 limb 加第 k 个 limb 上的进位组成的 t < 2n 做最终减法
+    #[inline(always)]
+    fn sub_if_ge(&self, mut t: [Limb; LIMBS], hi: Limb) -> Uint<LIMBS> {
+        if self.k < LIMBS {
+            t[self.k] = hi;
+            Uint::new(t).ct_sub_if_ge(&self.n)
+        } else {
+            Uint::new(t).ct_sub_if_ge_with_carry(hi, &self.n)
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct TrimmedForm<'a, const LIMBS: usize> {
+    form: Uint<LIMBS>,
+    params: &'a TrimmedMontyParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> LadderOps for TrimmedForm<'_, LIMBS> {
+    #[inline(always)]
+    fn ladder_mul(&self, rhs: &Self) -> Self {
+        Self {
+            form: self.params.mul(&self.form, &rhs.form),
+            params: self.params,
+        }
+    }
+
+    #[inline(always)]
+    fn ladder_square(&self) -> Self {
+        Self {
+            form: self.params.square(&self.form),
+            params: self.params,
+        }
+    }
+
+    #[inline(always)]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        Uint::ct_swap(&mut a.form, &mut b.form, choice);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::monty::trimmed::TrimmedMontyParams;
+    use crate::num::monty::MontyParams;
+    use crate::num::uint::{U256, U64};
+
+    #[test]
+    fn test_matches_monty_params() {
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            // 模数占 1 到 4 个 limb
+            let n = U256::rand(&mut rng)
+                .wrapping_shr(rng.gen_range(0..250))
+                .bitor(&U256::ONE);
+            let params = TrimmedMontyParams::init(&n).unwrap();
+            let full = MontyParams::init(&n).unwrap();
+            let a = U256::rand_mod(&mut rng, &n);
+            let b = U256::rand_mod(&mut rng, &n);
+
+            let ma = params.monty_form(&a);
+            let mb = params.monty_form(&b);
+            assert_eq!(a, params.normalize(&ma));
+            assert_eq!(
+                a.mul_mod(&b, &n),
+                params.normalize(&params.mul(&ma, &mb)),
+                "n: {}",
+                n
+            );
+            assert_eq!(a.mul_mod(&a, &n), params.normalize(&params.square(&ma)));
+
+            let e = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..256));
+            let expect = full.to_monty_form(&a).pow(&e).normalize();
+            let pow = params.pow_ct(&ma, &e, e.bits());
+            assert_eq!(expect, params.normalize(&pow));
+        }
+    }
+
+    #[test]
+    fn test_monty_form_wide_input() {
+        // x 可以大于 n，只要 x < n * 2^{64k}
+        let mut rng = thread_rng();
+        let n = U256::rand(&mut rng)
+            .wrapping_shr(129)
+            .bitor(&U256::ONE.wrapping_shl(127))
+            .bitor(&U256::ONE);
+        let params = TrimmedMontyParams::init(&n).unwrap();
+        for _ in 0..100 {
+            let x = U256::rand(&mut rng).wrapping_shr(1);
+            let mx = params.monty_form(&x);
+            assert!(mx < params.n);
+            assert_eq!(x % n, params.normalize(&mx));
+
+            // n 的最高 limb 已满，中间结果会进位到第 k 个 limb
+            let y = U256::rand_mod(&mut rng, &n);
+            let my = params.monty_form(&y);
+            let expect = (x % n).mul_mod(&y, &n);
+            assert_eq!(expect, params.normalize(&params.mul(&mx, &my)));
+            assert_eq!(y.mul_mod(&y, &n), params.normalize(&params.square(&my)));
+        }
+    }
+
+    #[test]
+    fn test_rejects_even() {
+        assert!(TrimmedMontyParams::init(&U64::ZERO).is_none());
+        assert!(TrimmedMontyParams::init(&U64::from_u64(10)).is_none());
+    }
+}
@@ -1,42 +1,71 @@
+pub(crate) mod karatsuba;
+
 use crate::num::limb::Limb;
+use crate::num::mul::karatsuba::{
+    karatsuba_mul, karatsuba_square, schoolbook_mul, schoolbook_square, KARATSUBA_THRESHOLD,
+};
 use crate::num::uint::Uint;
 use crate::num::wide::Wide;
-use std::ops::Mul;
+use core::ops::Mul;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
         let r = self.split_mul(rhs);
         (r.low, r.high.is_nonzero())
     }
 
     #[inline(always)]
-    pub(crate) fn split_mul(&self, rhs: &Self) -> Wide<LIMBS> {
-        let mut temp = vec![Limb::ZERO; LIMBS * 2];
-
-        for i in 0..LIMBS {
-            let mut carry = Limb::ZERO;
-            for j in 0..LIMBS {
-                let (ret, c) = temp[i + j].mac(self.limbs[i], rhs.limbs[j], carry);
-                carry = c;
-                temp[i + j] = ret;
-            }
-            temp[i + LIMBS] = carry;
+    pub fn split_mul(&self, rhs: &Self) -> Wide<LIMBS> {
+        let mut buf = [[Limb::ZERO; LIMBS]; 2];
+
+        if LIMBS >= KARATSUBA_THRESHOLD {
+            let mut scratch = [[Limb::ZERO; LIMBS]; 8];
+            karatsuba_mul(
+                &self.limbs,
+                &rhs.limbs,
+                buf.as_flattened_mut(),
+                scratch.as_flattened_mut(),
+            );
+        } else {
+            schoolbook_mul(&self.limbs, &rhs.limbs, buf.as_flattened_mut());
         }
 
-        let mut low = [Limb::ZERO; LIMBS];
-        low.copy_from_slice(&temp[0..LIMBS]);
-        let mut high = [Limb::ZERO; LIMBS];
-        high.copy_from_slice(&temp[LIMBS..LIMBS * 2]);
+        Wide {
+            low: Self { limbs: buf[0] },
+            high: Self { limbs: buf[1] },
+        }
+    }
+
+    /// self^2，交叉项只计算一次再加倍，约为一般乘法的一半到三分之二的开销
+    #[inline(always)]
+    pub fn square_wide(&self) -> Wide<LIMBS> {
+        let mut buf = [[Limb::ZERO; LIMBS]; 2];
+
+        if LIMBS >= KARATSUBA_THRESHOLD {
+            let mut scratch = [[Limb::ZERO; LIMBS]; 8];
+            karatsuba_square(
+                &self.limbs,
+                buf.as_flattened_mut(),
+                scratch.as_flattened_mut(),
+            );
+        } else {
+            schoolbook_square(&self.limbs, buf.as_flattened_mut());
+        }
 
         Wide {
-            low: Self { limbs: low },
-            high: Self { limbs: high },
+            low: Self { limbs: buf[0] },
+            high: Self { limbs: buf[1] },
         }
     }
 
     #[inline(always)]
-    pub(crate) fn wrapping_mul(&self, rhs: &Self) -> Self {
+    pub fn wrapping_square(&self) -> Self {
+        self.square_wide().low
+    }
+
+    #[inline(always)]
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
         self.split_mul(rhs).low
     }
 }
@@ -52,7 +81,7 @@ impl<const LIMBS: usize> Mul<&Uint<LIMBS>> for Uint<LIMBS> {
 
 #[cfg(test)]
 mod test {
-    use crate::num::uint::U128;
+    use crate::num::uint::{U128, U4096};
     use rand::{thread_rng, Rng};
 
     #[test]
@@ -74,6 +103,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_split_mul_large() {
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            // 超过 Karatsuba 阈值，平方与一般乘法结果一致
+            let a = U4096::rand(&mut rng);
+            assert_eq!(a.split_mul(&a), a.square_wide());
+
+            // (a + 1)^2 = a^2 + 2a + 1 (取低半部分)
+            let a1 = a.wrapping_add(&U4096::ONE);
+            let expect = a
+                .wrapping_mul(&a)
+                .wrapping_add(&a.wrapping_shl1())
+                .wrapping_add(&U4096::ONE);
+            assert_eq!(expect, a1.wrapping_square());
+        }
+    }
+
+    #[test]
+    fn test_square_wide() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a = U128::rand(&mut rng);
+            assert_eq!(a.split_mul(&a), a.square_wide());
+        }
+        assert_eq!(U128::MAX.split_mul(&U128::MAX), U128::MAX.square_wide());
+        assert_eq!(U128::ZERO, U128::ZERO.square_wide().high);
+    }
+
     #[test]
     fn test_split_mul() {
         let a = U128::from(230679353788795331459744549142118481455u128);
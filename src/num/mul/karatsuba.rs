@@ -0,0 +1,281 @@
+use crate::num::choice::Choice;
+use crate::num::limb::Limb;
+
+/// 低于该 limb 数时使用教科书乘法
+///
+/// 取值为经验估计，未针对具体平台测量两种算法的交叉点
+pub(crate) const KARATSUBA_THRESHOLD: usize = 40;
+
+/// Karatsuba 递归所需的临时空间 (limb 数) 上界，调用方按 `8 * n` 分配即可满足
+#[inline(always)]
+pub(crate) const fn scratch_len(n: usize) -> usize {
+    if n < KARATSUBA_THRESHOLD {
+        0
+    } else {
+        let hh = n - n / 2;
+        6 * hh + 1 + scratch_len(hh)
+    }
+}
+
+/// out = a * b，out 的长度为 a.len() + b.len()
+#[inline(always)]
+pub(crate) fn schoolbook_mul(a: &[Limb], b: &[Limb], out: &mut [Limb]) {
+    out.fill(Limb::ZERO);
+    for i in 0..a.len() {
+        let mut carry = Limb::ZERO;
+        for j in 0..b.len() {
+            let (ret, c) = out[i + j].mac(a[i], b[j], carry);
+            carry = c;
+            out[i + j] = ret;
+        }
+        out[i + b.len()] = carry;
+    }
+}
+
+/// out = a^2，交叉项 a_i * a_j (i < j) 只计算一次再整体左移一位，最后加上对角项 a_i^2
+#[inline(always)]
+pub(crate) fn schoolbook_square(a: &[Limb], out: &mut [Limb]) {
+    let n = a.len();
+    out.fill(Limb::ZERO);
+
+    for i in 0..n {
+        let mut carry = Limb::ZERO;
+        for j in (i + 1)..n {
+            let (ret, c) = out[i + j].mac(a[i], a[j], carry);
+            carry = c;
+            out[i + j] = ret;
+        }
+        out[i + n] = carry;
+    }
+
+    // 交叉项之和乘 2
+    let mut high = Limb::ZERO;
+    for limb in out.iter_mut() {
+        let next = Limb(limb.0 >> (Limb::BITS - 1));
+        *limb = Limb((limb.0 << 1) | high.0);
+        high = next;
+    }
+
+    let mut carry = Limb::ZERO;
+    for i in 0..n {
+        let (lo, c) = out[2 * i].mac(a[i], a[i], carry);
+        out[2 * i] = lo;
+        let (hi, c) = out[2 * i + 1].adc(c, Limb::ZERO);
+        out[2 * i + 1] = hi;
+        carry = c;
+    }
+}
+
+/// out = a * b，a、b 长度相同，长度不低于阈值时使用 Karatsuba 递归
+///
+/// 采用减法形式: a0 * b1 + a1 * b0 = a0 * b0 + a1 * b1 + (a0 - a1)(b1 - b0)，
+/// 差的符号通过掩码处理，不引入数据相关的分支
+pub(crate) fn karatsuba_mul(a: &[Limb], b: &[Limb], out: &mut [Limb], scratch: &mut [Limb]) {
+    let n = a.len();
+    debug_assert_eq!(n, b.len());
+    debug_assert!(scratch.len() >= scratch_len(n));
+    if n < KARATSUBA_THRESHOLD {
+        schoolbook_mul(a, b, out);
+        return;
+    }
+
+    let h = n / 2;
+    let hh = n - h;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+
+    let (da, rest) = scratch.split_at_mut(hh);
+    let (db, rest) = rest.split_at_mut(hh);
+    let (mid, rest) = rest.split_at_mut(2 * hh);
+    let (t, rest) = rest.split_at_mut(2 * hh + 1);
+
+    // da = |a0 - a1|, db = |b0 - b1|，两差同号时 (a0 - a1)(b1 - b0) 为负
+    let sign_a = abs_diff(a0, a1, da);
+    let sign_b = abs_diff(b0, b1, db);
+
+    karatsuba_mul(a0, b0, &mut out[..2 * h], rest);
+    karatsuba_mul(a1, b1, &mut out[2 * h..], rest);
+    karatsuba_mul(da, db, mid, rest);
+
+    combine(out, h, mid, !(sign_a ^ sign_b), t);
+}
+
+/// out = a^2，Karatsuba 平方: 2 * a0 * a1 = a0^2 + a1^2 - (a0 - a1)^2
+pub(crate) fn karatsuba_square(a: &[Limb], out: &mut [Limb], scratch: &mut [Limb]) {
+    let n = a.len();
+    debug_assert!(scratch.len() >= scratch_len(n));
+    if n < KARATSUBA_THRESHOLD {
+        schoolbook_square(a, out);
+        return;
+    }
+
+    let h = n / 2;
+    let hh = n - h;
+    let (a0, a1) = a.split_at(h);
+
+    let (da, rest) = scratch.split_at_mut(hh);
+    let (mid, rest) = rest.split_at_mut(2 * hh);
+    let (t, rest) = rest.split_at_mut(2 * hh + 1);
+
+    abs_diff(a0, a1, da);
+
+    karatsuba_square(a0, &mut out[..2 * h], rest);
+    karatsuba_square(a1, &mut out[2 * h..], rest);
+    karatsuba_square(da, mid, rest);
+
+    combine(out, h, mid, Choice::TRUE, t);
+}
+
+/// out = |x - y|，x 的长度不超过 y (不足部分视为 0)，返回 x < y
+#[inline(always)]
+fn abs_diff(x: &[Limb], y: &[Limb], out: &mut [Limb]) -> Choice {
+    let mut borrow = Limb::ZERO;
+    for i in 0..y.len() {
+        let xi = if i < x.len() { x[i] } else { Limb::ZERO };
+        let (d, b) = xi.sbb(y[i], borrow);
+        out[i] = d;
+        borrow = b;
+    }
+    let negative = Choice::from_mask(borrow.0);
+    conditional_negate(out, negative);
+    negative
+}
+
+/// choice 为真时 x = -x (模 2^{64 * x.len()} 的补码)
+#[inline(always)]
+fn conditional_negate(x: &mut [Limb], choice: Choice) {
+    let mask = choice.mask();
+    let mut carry = Limb(mask & 1);
+    for limb in x.iter_mut() {
+        let (r, c) = Limb(limb.0 ^ mask).adc(carry, Limb::ZERO);
+        *limb = r;
+        carry = c;
+    }
+}
+
+/// 将中间项 z0 + z2 - mid (negative 为真) 或 z0 + z2 + mid 加到 out 的第 h 个 limb 起
+#[inline(always)]
+fn combine(out: &mut [Limb], h: usize, mid: &[Limb], negative: Choice, t: &mut [Limb]) {
+    let len = mid.len();
+    let (z0, z2) = out.split_at(2 * h);
+
+    // t = z0 + z2
+    let mut carry = Limb::ZERO;
+    for i in 0..len {
+        let x = if i < z0.len() { z0[i] } else { Limb::ZERO };
+        let (s, c) = x.adc(z2[i], carry);
+        t[i] = s;
+        carry = c;
+    }
+    t[len] = carry;
+
+    // t += ±mid，结果必为非负且不超过 len + 1 个 limb，最高位的溢出可以丢弃
+    let mask = negative.mask();
+    let mut carry = Limb(mask & 1);
+    for i in 0..=len {
+        let m = if i < len { mid[i] } else { Limb::ZERO };
+        let (s, c) = t[i].adc(Limb(m.0 ^ mask), carry);
+        t[i] = s;
+        carry = c;
+    }
+
+    let mut carry = Limb::ZERO;
+    for i in 0..=len {
+        let (s, c) = out[h + i].adc(t[i], carry);
+        out[h + i] = s;
+        carry = c;
+    }
+    for limb in out[h + len + 1..].iter_mut() {
+        let (s, c) = limb.adc(carry, Limb::ZERO);
+        *limb = s;
+        carry = c;
+    }
+    debug_assert!(carry.is_zero());
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::limb::Limb;
+    use crate::num::mul::karatsuba::{
+        karatsuba_mul, karatsuba_square, schoolbook_mul, schoolbook_square, scratch_len,
+        KARATSUBA_THRESHOLD,
+    };
+
+    fn rand_limbs(rng: &mut impl Rng, n: usize) -> Vec<Limb> {
+        (0..n)
+            .map(|_| match rng.gen_range(0..4) {
+                // 全 1 与全 0 的 limb 覆盖差值符号与进位的边界
+                0 => Limb::MAX,
+                1 => Limb::ZERO,
+                _ => Limb(rng.gen()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_karatsuba_mul() {
+        let mut rng = thread_rng();
+        for n in [
+            1,
+            7,
+            KARATSUBA_THRESHOLD,
+            KARATSUBA_THRESHOLD + 1,
+            2 * KARATSUBA_THRESHOLD + 3,
+            128,
+        ] {
+            for _ in 0..20 {
+                let a = rand_limbs(&mut rng, n);
+                let b = rand_limbs(&mut rng, n);
+                let mut expect = vec![Limb::ZERO; 2 * n];
+                let mut actual = vec![Limb::ZERO; 2 * n];
+                let mut scratch = vec![Limb::ZERO; scratch_len(n)];
+                schoolbook_mul(&a, &b, &mut expect);
+                karatsuba_mul(&a, &b, &mut actual, &mut scratch);
+                assert_eq!(expect, actual, "n: {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_karatsuba_square() {
+        let mut rng = thread_rng();
+        for n in [
+            1,
+            2,
+            7,
+            KARATSUBA_THRESHOLD,
+            2 * KARATSUBA_THRESHOLD + 3,
+            128,
+        ] {
+            for _ in 0..20 {
+                let a = rand_limbs(&mut rng, n);
+                let mut expect = vec![Limb::ZERO; 2 * n];
+                let mut square = vec![Limb::ZERO; 2 * n];
+                let mut actual = vec![Limb::ZERO; 2 * n];
+                let mut scratch = vec![Limb::ZERO; scratch_len(n)];
+                schoolbook_mul(&a, &a, &mut expect);
+                schoolbook_square(&a, &mut square);
+                karatsuba_square(&a, &mut actual, &mut scratch);
+                assert_eq!(expect, square, "n: {}", n);
+                assert_eq!(expect, actual, "n: {}", n);
+            }
+        }
+
+        // 全 1: (2^{64n} - 1)^2
+        let a = vec![Limb::MAX; 64];
+        let mut expect = vec![Limb::ZERO; 128];
+        let mut actual = vec![Limb::ZERO; 128];
+        schoolbook_mul(&a, &a, &mut expect);
+        karatsuba_square(&a, &mut actual, &mut vec![Limb::ZERO; scratch_len(64)]);
+        assert_eq!(expect, actual);
+    }
+
+    #[test]
+    fn test_scratch_len() {
+        for n in KARATSUBA_THRESHOLD..=256 {
+            assert!(scratch_len(n) <= 8 * n, "n: {}", n);
+        }
+    }
+}
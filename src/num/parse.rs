@@ -0,0 +1,223 @@
+use core::str::FromStr;
+
+use crate::num::error::ParseError;
+use crate::num::limb::Limb;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// self * m + a，返回结果与溢出的高位 limb
+    #[inline(always)]
+    fn mul_add_limb(&self, m: Limb, a: Limb) -> (Self, Limb) {
+        let mut ret = Self::ZERO;
+        let mut carry = a;
+        for i in 0..LIMBS {
+            let (w, c) = Limb::ZERO.mac(self.limbs[i], m, carry);
+            ret.limbs[i] = w;
+            carry = c;
+        }
+        (ret, carry)
+    }
+
+    /// 按给定进制 (2 到 36) 解析，允许一个前导 `+`，字母不区分大小写
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]`"
+        );
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut ret = Self::ZERO;
+        for c in digits.chars() {
+            let d = c.to_digit(radix).ok_or(ParseError::InvalidDigit)?;
+            let (next, overflow) = ret.mul_add_limb(Limb(radix as u64), Limb(d as u64));
+            if overflow.is_nonzero() {
+                return Err(ParseError::Overflow);
+            }
+            ret = next;
+        }
+        Ok(ret)
+    }
+
+    /// 大端序十六进制，允许 `0x` 前缀与 `_` 分隔符
+    pub fn from_be_hex(s: &str) -> Result<Self, ParseError> {
+        let mut ret = Self::ZERO;
+        let mut empty = true;
+        for c in strip_hex_prefix(s).chars().filter(|&c| c != '_') {
+            let d = c.to_digit(16).ok_or(ParseError::InvalidDigit)?;
+            // 最高的 4 位非零时左移将溢出
+            if ret.limbs[LIMBS - 1].0 >> (Limb::BITS - 4) != 0 {
+                return Err(ParseError::Overflow);
+            }
+            ret = ret.wrapping_shl(4);
+            ret.limbs[0].0 |= d as u64;
+            empty = false;
+        }
+        if empty {
+            return Err(ParseError::Empty);
+        }
+        Ok(ret)
+    }
+
+    /// 小端序十六进制: 每两个数字为一个字节，最低有效字节在前，允许 `0x` 前缀与 `_` 分隔符
+    pub fn from_le_hex(s: &str) -> Result<Self, ParseError> {
+        let mut ret = Self::ZERO;
+        let mut count = 0;
+        for c in strip_hex_prefix(s).chars().filter(|&c| c != '_') {
+            let d = c.to_digit(16).ok_or(ParseError::InvalidDigit)? as u64;
+            // 第 count 个数字属于第 count / 2 个字节，字节内高半字节在前
+            let byte = count / 2;
+            if d != 0 {
+                if byte >= Limb::BYTES * LIMBS {
+                    return Err(ParseError::Overflow);
+                }
+                let shift = (byte % Limb::BYTES) * 8 + if count % 2 == 0 { 4 } else { 0 };
+                ret.limbs[byte / Limb::BYTES].0 |= d << shift;
+            }
+            count += 1;
+        }
+        match count {
+            0 => Err(ParseError::Empty),
+            _ if count % 2 == 1 => Err(ParseError::InvalidLength),
+            _ => Ok(ret),
+        }
+    }
+}
+
+#[inline(always)]
+fn strip_hex_prefix(s: &str) -> &str {
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+}
+
+impl<const LIMBS: usize> FromStr for Uint<LIMBS> {
+    type Err = ParseError;
+
+    /// 十进制
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{thread_rng, Rng};
+
+    use crate::num::error::ParseError;
+    use crate::num::uint::{U128, U256, U64};
+
+    #[test]
+    fn test_from_str() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            assert_eq!(Ok(U128::from_u128(a)), a.to_string().parse());
+        }
+        assert_eq!(Ok(U256::MAX), U256::MAX.to_string().parse());
+        assert_eq!(Ok(U64::from_u64(42)), "+42".parse());
+        assert_eq!(Ok(U64::ZERO), "000".parse());
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let radix = rng.gen_range(2..=36);
+            let s = to_radix(a, radix);
+            assert_eq!(
+                Ok(U64::from_u64(a)),
+                U64::from_str_radix(&s, radix),
+                "s: {}",
+                s
+            );
+            assert_eq!(
+                Ok(U64::from_u64(a)),
+                U64::from_str_radix(&s.to_uppercase(), radix)
+            );
+        }
+        assert_eq!(Ok(U128::from_u128(0b1011)), U128::from_str_radix("1011", 2));
+    }
+
+    fn to_radix(mut a: u64, radix: u32) -> String {
+        let mut s = String::new();
+        loop {
+            s.insert(
+                0,
+                char::from_digit((a % radix as u64) as u32, radix).unwrap(),
+            );
+            a /= radix as u64;
+            if a == 0 {
+                return s;
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(ParseError::Empty), "".parse::<U64>());
+        assert_eq!(Err(ParseError::Empty), "+".parse::<U64>());
+        assert_eq!(Err(ParseError::InvalidDigit), "12a".parse::<U64>());
+        assert_eq!(Err(ParseError::InvalidDigit), "-1".parse::<U64>());
+        assert_eq!(Err(ParseError::InvalidDigit), U64::from_str_radix("2", 2));
+        // 2^64
+        assert_eq!(
+            Err(ParseError::Overflow),
+            "18446744073709551616".parse::<U64>()
+        );
+        assert_eq!(Ok(U64::MAX), "18446744073709551615".parse::<U64>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_radix() {
+        let _ = U64::from_str_radix("1", 37);
+    }
+
+    #[test]
+    fn test_from_be_hex() {
+        assert_eq!(
+            Ok(U128::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210)),
+            U128::from_be_hex("0x0123_4567_89AB_CDEF_fedc_ba98_7654_3210")
+        );
+        assert_eq!(Ok(U64::from_u64(0xff)), U64::from_be_hex("ff"));
+        // 前导零不计入溢出
+        assert_eq!(
+            Ok(U64::ONE),
+            U64::from_be_hex("0000000000000000000000000001")
+        );
+        assert_eq!(
+            Err(ParseError::Overflow),
+            U64::from_be_hex("1_0000_0000_0000_0000")
+        );
+        assert_eq!(Err(ParseError::Empty), U64::from_be_hex("0x"));
+        assert_eq!(Err(ParseError::Empty), U64::from_be_hex("__"));
+        assert_eq!(Err(ParseError::InvalidDigit), U64::from_be_hex("0xg"));
+    }
+
+    #[test]
+    fn test_from_le_hex() {
+        assert_eq!(Ok(U64::ONE), U64::from_le_hex("0100"));
+        assert_eq!(Ok(U64::from_u64(0x1234)), U64::from_le_hex("0x34_12"));
+        assert_eq!(
+            Ok(U128::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210)),
+            U128::from_le_hex("1032547698badcfeefcdab8967452301")
+        );
+        // 高位多余的零字节
+        assert_eq!(
+            Ok(U64::from_u64(0xab)),
+            U64::from_le_hex("ab00000000000000000000")
+        );
+        assert_eq!(
+            Err(ParseError::Overflow),
+            U64::from_le_hex("000000000000000001")
+        );
+        assert_eq!(Err(ParseError::InvalidLength), U64::from_le_hex("123"));
+        assert_eq!(Err(ParseError::Empty), U64::from_le_hex(""));
+    }
+}
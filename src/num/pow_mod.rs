@@ -1,38 +1,220 @@
+use crate::num::barrett::BarrettParams;
+use crate::num::monty::MontyParams;
 use crate::num::uint::Uint;
 
+/// 滑动窗口的最大宽度，预计算表最多包含 2^{MAX_WINDOW - 1} 个奇数次幂
+pub(crate) const MAX_WINDOW: usize = 6;
+
+/// 指数: 可以按位访问的无符号整数
+pub trait Exponent {
+    /// 有效位数
+    fn bits(&self) -> usize;
+
+    /// 第 index 位是否为 1
+    fn bit(&self, index: usize) -> bool;
+}
+
+macro_rules! impl_exponent {
+    ($($t:ty),+) => {
+        $(
+            impl Exponent for $t {
+                #[inline(always)]
+                fn bits(&self) -> usize {
+                    (<$t>::BITS - self.leading_zeros()) as usize
+                }
+
+                #[inline(always)]
+                fn bit(&self, index: usize) -> bool {
+                    (self >> index) & 1 == 1
+                }
+            }
+        )+
+    };
+}
+
+impl_exponent!(u32, u64, u128);
+
+impl<const LIMBS: usize> Exponent for Uint<LIMBS> {
+    #[inline(always)]
+    fn bits(&self) -> usize {
+        Uint::bits(self)
+    }
+
+    #[inline(always)]
+    fn bit(&self, index: usize) -> bool {
+        Uint::bit(self, index)
+    }
+}
+
+/// 根据指数位数选择滑动窗口宽度，使预计算与乘法次数之和最小
+#[inline(always)]
+pub(crate) fn window_size(bits: usize) -> usize {
+    match bits {
+        0..=23 => 1,
+        24..=79 => 3,
+        80..=239 => 4,
+        240..=671 => 5,
+        _ => MAX_WINDOW,
+    }
+}
+
+/// 从高位到低位的滑动窗口扫描，每一项为 (平方次数, 窗口值)
+///
+/// 连续的 0 位单独成项且没有窗口值；窗口值总为奇数，对应奇数次幂表中的下标 `value >> 1`
+pub(crate) struct SlidingWindows<'a, X: Exponent + ?Sized> {
+    exp: &'a X,
+    window: usize,
+    /// 尚未扫描的最高位之上一位
+    index: usize,
+}
+
+impl<'a, X: Exponent + ?Sized> SlidingWindows<'a, X> {
+    pub(crate) fn new(exp: &'a X, window: usize) -> Self {
+        Self {
+            exp,
+            window,
+            index: exp.bits(),
+        }
+    }
+}
+
+impl<X: Exponent + ?Sized> Iterator for SlidingWindows<'_, X> {
+    type Item = (usize, Option<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.index;
+        if i == 0 {
+            return None;
+        }
+        if !self.exp.bit(i - 1) {
+            self.index -= 1;
+            return Some((1, None));
+        }
+
+        // 窗口 [low, i)，最低位为 1
+        let mut low = i.saturating_sub(self.window);
+        while !self.exp.bit(low) {
+            low += 1;
+        }
+
+        let mut value = 0;
+        for j in (low..i).rev() {
+            value = (value << 1) | self.exp.bit(j) as usize;
+        }
+
+        self.index = low;
+        Some((i - low, Some(value)))
+    }
+}
+
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
     /// 2^k \mod n
-    pub fn pow2k_mod(k: u32, n: &Self) -> Self {
+    pub fn pow2k_mod<X: Exponent + ?Sized>(k: &X, n: &Self) -> Self {
         Uint::from(2u64).mod_exp(k, n)
     }
 
     #[inline(always)]
-    /// self^{k} \mod n
-    pub fn mod_exp(&self, exp: u32, n: &Self) -> Self {
-        let mut result = Uint::ONE;
-        let mut base = *self;
-        let mut exp = exp;
-
-        while exp > 0 {
-            if exp % 2 == 1 {
-                result = result.mul_mod(&base, n);
-            }
-            base = base.mul_mod(&base, n);
-            exp /= 2;
+    /// self^{exp} \mod n，从左到右滑动窗口
+    pub fn mod_exp<X: Exponent + ?Sized>(&self, exp: &X, n: &Self) -> Self {
+        // 奇数模数直接在蒙哥马利空间中完成整个指数运算
+        if n.is_odd() && *n > Self::ONE {
+            let params = MontyParams::init(n).expect("modulus must be odd");
+            return params.to_monty_form(self).pow(exp).normalize();
         }
 
-        result
+        // 偶数模数使用 Barrett 约减
+        let params = BarrettParams::init(n)
+            .expect("attempt to calculate the remainder with a divisor of zero");
+        params.pow_mod(self, exp)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::num::uint::U64;
+    use crate::exponent::fast_modular_exponentiation;
+    use crate::num::pow_mod::Exponent;
+    use crate::num::uint::{Uint, U128, U256, U64};
+    use rand::{thread_rng, Rng};
+
+    /// 逐位平方乘，作为对照
+    fn naive_mod_exp<const LIMBS: usize, X: Exponent>(
+        base: &Uint<LIMBS>,
+        exp: &X,
+        n: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        let mut result = Uint::ONE % n;
+        for i in (0..exp.bits()).rev() {
+            result = result.mul_mod(&result, n);
+            if exp.bit(i) {
+                result = result.mul_mod(base, n);
+            }
+        }
+        result
+    }
 
     #[test]
     fn test_pow2k_mod() {
-        let actual = U64::pow2k_mod(U64::BITS as u32, &U64::from_u64(3123123));
+        let actual = U64::pow2k_mod(&(U64::BITS as u32), &U64::from_u64(3123123));
         assert_eq!(U64::from(294187u64), actual)
     }
+
+    #[test]
+    fn test_pow2k_mod_wide() {
+        // 2^{2^64} mod n
+        let k = U128::from_u128(1u128 << 64);
+        let n = U64::from_u64(1000000007);
+        let expect = U64::from_u64(2).mod_exp(&(1u128 << 64), &n);
+        assert_eq!(expect, U64::pow2k_mod(&k, &n));
+    }
+
+    #[test]
+    fn test_mod_exp_u64() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let e: u64 = rng.gen();
+            let m: u64 = rng.gen_range(1..=u64::MAX);
+            assert_eq!(
+                U64::from_u64(fast_modular_exponentiation(a, e, m)),
+                U64::from_u64(a).mod_exp(&e, &U64::from_u64(m)),
+                "a: {} e: {} m: {}",
+                a,
+                e,
+                m
+            );
+        }
+    }
+
+    #[test]
+    fn test_mod_exp_wide_exponent() {
+        let mut rng = thread_rng();
+        for _ in 0..20 {
+            let a = U128::rand(&mut rng);
+            let e = U256::rand(&mut rng).wrapping_shr(rng.gen_range(0..256));
+            let n = U128::rand(&mut rng).wrapping_shr(rng.gen_range(0..127));
+            if n.is_zero() {
+                continue;
+            }
+            assert_eq!(
+                naive_mod_exp(&a, &e, &n),
+                a.mod_exp(&e, &n),
+                "a: {} e: {} n: {}",
+                a,
+                e,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_mod_exp_edge() {
+        let n = U128::from_u64(1000000007);
+        assert_eq!(U128::ONE, U128::from_u64(5).mod_exp(&0u32, &n));
+        assert_eq!(U128::ZERO, U128::from_u64(5).mod_exp(&0u32, &U128::ONE));
+        assert_eq!(U128::ZERO, U128::ZERO.mod_exp(&U128::MAX, &n));
+        // 费马小定理
+        let e = n - &U128::ONE;
+        assert_eq!(U128::ONE, U128::from_u64(123456789).mod_exp(&e, &n));
+    }
 }
@@ -0,0 +1,127 @@
+mod generate;
+mod lucas;
+mod miller_rabin;
+mod safe;
+mod sieve;
+mod strong;
+
+use rand_core::CryptoRngCore;
+
+use crate::num::limb::Limb;
+use crate::num::prime::miller_rabin::MillerRabin;
+use crate::num::uint::Uint;
+
+pub use generate::PrimeOptions;
+
+/// 用于试除的小素数个数
+const SMALL_PRIMES_COUNT: usize = 256;
+
+/// 前 `SMALL_PRIMES_COUNT` 个奇素数
+pub(crate) const SMALL_PRIMES: [u64; SMALL_PRIMES_COUNT] = small_primes();
+
+const fn small_primes() -> [u64; SMALL_PRIMES_COUNT] {
+    let mut primes = [0u64; SMALL_PRIMES_COUNT];
+    let mut count = 0;
+    let mut candidate = 3u64;
+
+    while count < SMALL_PRIMES_COUNT {
+        let mut i = 0;
+        let mut is_prime = true;
+        while i < count && primes[i] * primes[i] <= candidate {
+            if candidate % primes[i] == 0 {
+                is_prime = false;
+                break;
+            }
+            i += 1;
+        }
+        if is_prime {
+            primes[count] = candidate;
+            count += 1;
+        }
+        candidate += 2;
+    }
+
+    primes
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Baillie-PSW 概率素性测试，之后再进行 `rounds` 轮随机底数的 Miller-Rabin 测试
+    ///
+    /// 1. 小素数试除
+    /// 2. 以 2 为底的强可能素数测试
+    /// 3. 强 Lucas 可能素数测试
+    pub fn is_probable_prime(&self, rounds: usize, rng: &mut impl CryptoRngCore) -> bool {
+        if *self < Self::from_u64(2) {
+            return false;
+        }
+        if self.is_even() {
+            return *self == Self::from_u64(2);
+        }
+
+        for p in SMALL_PRIMES {
+            if *self == Self::from_u64(p) {
+                return true;
+            }
+            if self.rem_limb(Limb(p)).is_zero() {
+                return false;
+            }
+        }
+
+        // 没有不超过 sqrt(self) 的素因子
+        let largest = SMALL_PRIMES[SMALL_PRIMES_COUNT - 1];
+        if *self < Self::from_u64(largest * largest) {
+            return true;
+        }
+
+        let mr = MillerRabin::new(self);
+        if !mr.test_base_two() || !self.strong_lucas() {
+            return false;
+        }
+
+        (0..rounds).all(|_| mr.test_random_base(rng))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::prime::SMALL_PRIMES;
+    use crate::num::uint::{U128, U256, U64};
+    use crate::wilson::is_prime2;
+
+    #[test]
+    fn test_small_primes() {
+        assert_eq!(3, SMALL_PRIMES[0]);
+        assert_eq!(1621, SMALL_PRIMES[255]);
+        assert!(SMALL_PRIMES.iter().all(|&p| is_prime2(p)));
+    }
+
+    #[test]
+    fn test_is_probable_prime_small() {
+        let mut rng = thread_rng();
+        for n in 0..5000u64 {
+            assert_eq!(
+                is_prime2(n),
+                U64::from_u64(n).is_probable_prime(0, &mut rng),
+                "n: {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_probable_prime_large() {
+        let mut rng = thread_rng();
+        // 2^127 - 1
+        assert!(U128::from_u128(u128::MAX >> 1).is_probable_prime(4, &mut rng));
+        // 2^255 - 19
+        let p25519 = U256::ONE.wrapping_shl(255) - &U256::from_u64(19);
+        assert!(p25519.is_probable_prime(4, &mut rng));
+        // 2^128 + 1
+        let f7 = U256::ONE.wrapping_shl(128) + &U256::ONE;
+        assert!(!f7.is_probable_prime(4, &mut rng));
+        // 3825123056546413051 为以 2, 3, 5, 7, 11, 13, 17, 19, 23 为底的强伪素数
+        assert!(!U64::from_u64(3825123056546413051).is_probable_prime(0, &mut rng));
+    }
+}
@@ -0,0 +1,219 @@
+use rand_core::CryptoRngCore;
+
+use crate::num::gcd::gcd;
+use crate::num::prime::sieve::Sieve;
+use crate::num::uint::Uint;
+
+/// 随机素数生成的约束条件
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimeOptions<const LIMBS: usize> {
+    /// 素数的精确位数
+    pub bits: usize,
+    /// 最高两位均为 1，保证两个此类素数的乘积恰好为 2 * bits 位
+    pub top_two_bits: bool,
+    /// (r, m): 要求 p ≡ r (mod m)
+    pub congruence: Option<(Uint<LIMBS>, Uint<LIMBS>)>,
+    /// e: 要求 gcd(p - 1, e) = 1
+    pub coprime_to: Option<Uint<LIMBS>>,
+    /// Baillie-PSW 之后追加的 Miller-Rabin 测试轮数
+    pub rounds: usize,
+}
+
+impl<const LIMBS: usize> PrimeOptions<LIMBS> {
+    pub fn new(bits: usize) -> Self {
+        Self {
+            bits,
+            top_two_bits: false,
+            congruence: None,
+            coprime_to: None,
+            rounds: 4,
+        }
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 生成 `bits` 位的随机素数
+    pub fn gen_prime(bits: usize, rng: &mut impl CryptoRngCore) -> Self {
+        Self::gen_prime_with(&PrimeOptions::new(bits), rng)
+    }
+
+    /// 按 `options` 的约束生成随机素数
+    ///
+    /// 随机选取起点后沿 p ≡ r (mod m) 的等差数列筛选，超出位数范围时重新选取起点。
+    /// gcd(r, m) > 1 时只有 r 本身可能满足约束，r 不满足时 panic
+    pub fn gen_prime_with(options: &PrimeOptions<LIMBS>, rng: &mut impl CryptoRngCore) -> Self {
+        let bits = options.bits;
+        assert!(
+            bits >= 2 && bits <= Self::BITS,
+            "prime bit length out of range"
+        );
+        assert!(
+            !options.top_two_bits || bits >= 3,
+            "top two bits requirement needs at least 3 bits"
+        );
+
+        let two = Self::from_u64(2);
+        let (residue, modulus) = options.congruence.unwrap_or((Self::ONE, two));
+        assert!(residue < modulus, "congruence residue must be reduced");
+        // 保证候选数均为奇数
+        let step = if modulus.is_odd() {
+            modulus.wrapping_shl1()
+        } else {
+            modulus
+        };
+
+        let top_bit = Self::ONE.wrapping_shl(bits as u32 - 1);
+        let min = if options.top_two_bits {
+            top_bit.bitor(&top_bit.wrapping_shr1())
+        } else {
+            top_bit
+        };
+        let max = Self::MAX.wrapping_shr((Self::BITS - bits) as u32);
+        let coprime = |candidate: &Self| match options.coprime_to {
+            Some(e) => gcd(&e, &(*candidate - &Self::ONE)) == Self::ONE,
+            None => true,
+        };
+
+        // gcd(r, m) > 1 时数列中每一项都是它的倍数，唯一可能的素数是 r 本身
+        if gcd(&residue, &modulus) != Self::ONE {
+            assert!(
+                residue >= min
+                    && residue <= max
+                    && coprime(&residue)
+                    && residue.is_probable_prime(options.rounds, rng),
+                "congruence admits no prime of the requested size"
+            );
+            return residue;
+        }
+
+        loop {
+            let mut start = Self::rand(rng).rem_2k(bits as u32).bitor(&min);
+
+            // start ≡ r (mod m)
+            let (base, overflow) = start.overflowing_sub(&(start % modulus));
+            start = base.wrapping_add(&residue);
+            if overflow || start < base || start < min {
+                continue;
+            }
+            if start.is_even() {
+                let (next, overflow) = start.overflowing_add(&modulus);
+                if overflow {
+                    continue;
+                }
+                start = next;
+            }
+
+            for candidate in Sieve::new(&start, &step, &max) {
+                if coprime(&candidate) && candidate.is_probable_prime(options.rounds, rng) {
+                    return candidate;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::gcd::gcd;
+    use crate::num::prime::PrimeOptions;
+    use crate::num::uint::{U128, U256, U64};
+    use crate::wilson::is_prime2;
+
+    #[test]
+    fn test_gen_prime_small() {
+        let mut rng = thread_rng();
+        for bits in 2..=40 {
+            for _ in 0..10 {
+                let p = U64::gen_prime(bits, &mut rng);
+                assert_eq!(bits, p.bits());
+                assert!(is_prime2(p.as_limbs()[0].0), "p: {}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gen_prime_bits() {
+        let mut rng = thread_rng();
+        for bits in [64, 100, 127, 128, 200, 256] {
+            let p = U256::gen_prime(bits, &mut rng);
+            assert_eq!(bits, p.bits());
+            assert!(p.miller_rabin(8, &mut rng));
+        }
+    }
+
+    #[test]
+    fn test_top_two_bits() {
+        let mut rng = thread_rng();
+        let options = PrimeOptions {
+            top_two_bits: true,
+            ..PrimeOptions::new(128)
+        };
+        for _ in 0..10 {
+            let p = U256::gen_prime_with(&options, &mut rng);
+            let q = U256::gen_prime_with(&options, &mut rng);
+            assert!(p.bit(127) && p.bit(126));
+            assert_eq!(256, (p * &q).bits());
+        }
+    }
+
+    #[test]
+    fn test_congruence() {
+        let mut rng = thread_rng();
+        let options = PrimeOptions {
+            congruence: Some((U128::from_u64(3), U128::from_u64(4))),
+            ..PrimeOptions::new(96)
+        };
+        for _ in 0..10 {
+            let p = U128::gen_prime_with(&options, &mut rng);
+            assert_eq!(96, p.bits());
+            assert_eq!(U128::from_u64(3), p % U128::from_u64(4));
+        }
+
+        // 奇数模数: p ≡ 2 (mod 3)
+        let options = PrimeOptions {
+            congruence: Some((U128::from_u64(2), U128::from_u64(3))),
+            ..PrimeOptions::new(96)
+        };
+        let p = U128::gen_prime_with(&options, &mut rng);
+        assert_eq!(U128::from_u64(2), p % U128::from_u64(3));
+    }
+
+    #[test]
+    fn test_congruence_not_coprime() {
+        let mut rng = thread_rng();
+        // p ≡ 3 (mod 6) 只有 3 本身是素数
+        let options = PrimeOptions {
+            congruence: Some((U64::from_u64(3), U64::from_u64(6))),
+            ..PrimeOptions::new(2)
+        };
+        assert_eq!(U64::from_u64(3), U64::gen_prime_with(&options, &mut rng));
+    }
+
+    #[test]
+    #[should_panic(expected = "congruence admits no prime")]
+    fn test_congruence_no_prime() {
+        let mut rng = thread_rng();
+        let options = PrimeOptions {
+            congruence: Some((U64::ZERO, U64::from_u64(3))),
+            ..PrimeOptions::new(32)
+        };
+        U64::gen_prime_with(&options, &mut rng);
+    }
+
+    #[test]
+    fn test_coprime_to() {
+        let mut rng = thread_rng();
+        // gcd(p - 1, 3 * 5 * 7) = 1
+        let e = U128::from_u64(105);
+        let options = PrimeOptions {
+            coprime_to: Some(e),
+            ..PrimeOptions::new(80)
+        };
+        for _ in 0..10 {
+            let p = U128::gen_prime_with(&options, &mut rng);
+            assert_eq!(U128::ONE, gcd(&e, &(p - &U128::ONE)));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::num::jacobi::jacobi;
+use crate::num::monty::{MontyForm, MontyParams};
+use crate::num::uint::Uint;
+
+/// x mod n，x 为有符号整数
+fn signed_mod<const LIMBS: usize>(x: i64, n: &Uint<LIMBS>) -> Uint<LIMBS> {
+    let r = Uint::from_u64(x.unsigned_abs()) % n;
+    if x < 0 && r.is_nonzero() {
+        *n - &r
+    } else {
+        r
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Selfridge 方法 A: 在 5, -7, 9, -11, ... 中寻找第一个满足 (D / n) = -1 的 D
+    ///
+    /// 返回 `None` 表示已确定 n 为合数
+    fn selfridge_d(&self) -> Option<i64> {
+        let mut d = 5i64;
+        loop {
+            match jacobi(&signed_mod(d, self), self) {
+                -1 => return Some(d),
+                // |D| < n 时说明 D 与 n 有公因子
+                0 if Uint::from_u64(d.unsigned_abs()) < *self => return None,
+                _ => {}
+            }
+
+            // 完全平方数不存在满足条件的 D
+            if d == 13 && self.is_square() {
+                return None;
+            }
+            d = if d > 0 { -(d + 2) } else { -(d - 2) };
+        }
+    }
+
+    /// 强 Lucas 可能素数测试 (P = 1, Q = (1 - D) / 4)，要求 self 为大于 2 的奇数
+    ///
+    /// 令 n + 1 = d * 2^s，若 U_d ≡ 0 或存在 0 <= r < s 使 V_{d * 2^r} ≡ 0 (mod n) 则通过
+    pub fn strong_lucas(&self) -> bool {
+        assert!(
+            self.is_odd() && *self > Self::ONE,
+            "strong Lucas test requires an odd modulus greater than 1"
+        );
+        let n = self;
+
+        let d_param = match self.selfridge_d() {
+            Some(d) => d,
+            None => return false,
+        };
+        let q_param = (1 - d_param) / 4;
+
+        // 全部运算在蒙哥马利空间中进行
+        let params = MontyParams::init(n).expect("modulus must be odd");
+        let d_form = params.to_monty_form(&signed_mod(d_param, n));
+        let q_form = params.to_monty_form(&signed_mod(q_param, n));
+
+        // n + 1 = d * 2^s
+        let (n_plus_one, overflow) = n.overflowing_add(&Self::ONE);
+        let (d, s) = if overflow {
+            (Self::ONE, Self::BITS as u32)
+        } else {
+            let s = n_plus_one.trailing_zeros();
+            (n_plus_one.wrapping_shr(s), s)
+        };
+
+        // 从 k = 1 开始: U_1 = 1, V_1 = P = 1, Q^1 = Q
+        let mut u = MontyForm::one(&params);
+        let mut v = MontyForm::one(&params);
+        let mut qk = q_form;
+
+        for i in (0..d.bits() - 1).rev() {
+            // U_{2k} = U_k * V_k, V_{2k} = V_k^2 - 2Q^k, Q^{2k} = (Q^k)^2
+            u = u.mul(&v);
+            v = v.square().sub(&qk.double());
+            qk = qk.square();
+
+            if d.bit(i) {
+                // U_{k+1} = (P * U_k + V_k) / 2, V_{k+1} = (D * U_k + P * V_k) / 2
+                let next_u = u.add(&v).div_by_2();
+                let next_v = d_form.mul(&u).add(&v).div_by_2();
+                u = next_u;
+                v = next_v;
+                qk = qk.mul(&q_form);
+            }
+        }
+
+        if u.is_zero() {
+            return true;
+        }
+
+        for _ in 0..s {
+            if v.is_zero() {
+                return true;
+            }
+            // V_{2k} = V_k^2 - 2Q^k
+            v = v.square().sub(&qk.double());
+            qk = qk.square();
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::prime::miller_rabin::MillerRabin;
+    use crate::num::uint::{U128, U64};
+    use crate::wilson::is_prime2;
+
+    /// 强 Lucas 伪素数 (OEIS A217255)
+    const STRONG_LUCAS_PSEUDOPRIMES: [u64; 10] = [
+        5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+    ];
+
+    #[test]
+    fn test_strong_lucas_small() {
+        for n in (3..6000u64).step_by(2) {
+            if STRONG_LUCAS_PSEUDOPRIMES.contains(&n) {
+                continue;
+            }
+            assert_eq!(is_prime2(n), U64::from_u64(n).strong_lucas(), "n: {}", n);
+        }
+    }
+
+    #[test]
+    fn test_strong_lucas_pseudoprimes() {
+        for n in STRONG_LUCAS_PSEUDOPRIMES {
+            let n = U64::from_u64(n);
+            assert!(n.strong_lucas(), "n: {}", n);
+            // 没有已知的数同时是以 2 为底的强伪素数和强 Lucas 伪素数
+            assert!(!MillerRabin::new(&n).test_base_two(), "n: {}", n);
+        }
+    }
+
+    #[test]
+    fn test_strong_lucas_large() {
+        // 2^127 - 1
+        assert!(U128::from_u128(u128::MAX >> 1).strong_lucas());
+        // (2^64 - 59)^2 为完全平方数
+        let p = U128::from_u64(u64::MAX - 58);
+        assert!(!(p * &p).strong_lucas());
+        // (2^61 - 1)(2^31 - 1)
+        let c = U128::from_u128(((1u128 << 61) - 1) * ((1u128 << 31) - 1));
+        assert!(!c.strong_lucas());
+    }
+}
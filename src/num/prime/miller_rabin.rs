@@ -0,0 +1,138 @@
+use rand_core::CryptoRngCore;
+
+use crate::num::monty::{MontyForm, MontyParams};
+use crate::num::uint::Uint;
+
+/// 对固定的奇数 n > 3 预计算 n - 1 = 2^s * d 及蒙哥马利参数，可对多个底数重复测试
+pub(crate) struct MillerRabin<const LIMBS: usize> {
+    n: Uint<LIMBS>,
+    d: Uint<LIMBS>,
+    s: u32,
+    params: MontyParams<LIMBS>,
+    one: MontyForm<LIMBS>,
+    minus_one: MontyForm<LIMBS>,
+}
+
+impl<const LIMBS: usize> MillerRabin<LIMBS> {
+    pub(crate) fn new(n: &Uint<LIMBS>) -> Self {
+        assert!(
+            n.is_odd() && *n > Uint::from_u64(3),
+            "Miller-Rabin requires an odd modulus greater than 3"
+        );
+
+        // n - 1 = 2^s * d, d 为奇数
+        let n_minus_one = *n - &Uint::ONE;
+        let s = n_minus_one.trailing_zeros();
+        let d = n_minus_one.wrapping_shr(s);
+
+        let params = MontyParams::init(n).expect("modulus must be odd");
+        Self {
+            n: *n,
+            d,
+            s,
+            params,
+            one: params.to_monty_form(&Uint::ONE),
+            minus_one: params.to_monty_form(&n_minus_one),
+        }
+    }
+
+    /// 以 `base` 为底的强可能素数测试
+    pub(crate) fn test(&self, base: &Uint<LIMBS>) -> bool {
+        let mut x = self.params.to_monty_form(base).pow(&self.d);
+        if x == self.one || x == self.minus_one {
+            return true;
+        }
+
+        for _ in 1..self.s {
+            x = x.square();
+            if x == self.minus_one {
+                return true;
+            }
+            if x == self.one {
+                return false;
+            }
+        }
+        false
+    }
+
+    pub(crate) fn test_base_two(&self) -> bool {
+        self.test(&Uint::from_u64(2))
+    }
+
+    /// 随机见证数 a \in [2, n - 2]
+    pub(crate) fn test_random_base(&self, rng: &mut impl CryptoRngCore) -> bool {
+        let two = Uint::from_u64(2);
+        let bound = self.n - &Uint::from_u64(3);
+        let base = Uint::rand_mod(rng, &bound) + &two;
+        self.test(&base)
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Miller-Rabin 概率素性测试，合数通过 `rounds` 轮测试的概率不超过 4^{-rounds}
+    pub fn miller_rabin(&self, rounds: usize, rng: &mut impl CryptoRngCore) -> bool {
+        let two = Self::from_u64(2);
+        let three = Self::from_u64(3);
+        if *self < two {
+            return false;
+        }
+        if *self == two || *self == three {
+            return true;
+        }
+        if self.is_even() {
+            return false;
+        }
+
+        let mr = MillerRabin::new(self);
+        (0..rounds).all(|_| mr.test_random_base(rng))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::prime::miller_rabin::MillerRabin;
+    use crate::num::uint::{U128, U256, U64};
+    use crate::wilson::is_prime2;
+
+    #[test]
+    fn test_miller_rabin_small() {
+        let mut rng = thread_rng();
+        for n in 0..2000u64 {
+            assert_eq!(
+                is_prime2(n),
+                U64::from_u64(n).miller_rabin(16, &mut rng),
+                "n: {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_miller_rabin_large() {
+        let mut rng = thread_rng();
+        // 2^127 - 1 为梅森素数
+        let m127 = U128::from_u128(u128::MAX >> 1);
+        assert!(m127.miller_rabin(16, &mut rng));
+        // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+        let f7 = U256::ONE.wrapping_shl(128) + &U256::ONE;
+        assert!(!f7.miller_rabin(16, &mut rng));
+        // Carmichael 数
+        for n in [561u64, 1105, 1729, 2465, 2821, 6601, 8911] {
+            assert!(!U64::from_u64(n).miller_rabin(16, &mut rng), "n: {}", n);
+        }
+    }
+
+    #[test]
+    fn test_base_two_pseudoprimes() {
+        // 以 2 为底的强伪素数
+        for n in [2047u64, 3277, 4033, 4681, 8321, 15841, 29341, 42799] {
+            assert!(
+                MillerRabin::new(&U64::from_u64(n)).test_base_two(),
+                "n: {}",
+                n
+            );
+        }
+    }
+}
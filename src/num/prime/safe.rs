@@ -0,0 +1,96 @@
+use rand_core::CryptoRngCore;
+
+use crate::num::prime::miller_rabin::MillerRabin;
+use crate::num::prime::sieve::Sieve;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 是否为安全素数: self 与 (self - 1) / 2 均为素数
+    pub fn is_safe_prime(&self, rounds: usize, rng: &mut impl CryptoRngCore) -> bool {
+        if *self < Self::from_u64(5) || self.is_even() {
+            return false;
+        }
+        let q = self.wrapping_shr1();
+        q.is_probable_prime(rounds, rng) && self.is_probable_prime(rounds, rng)
+    }
+
+    /// 生成 `bits` 位的安全素数 p = 2q + 1，q 为 `bits - 1` 位的素数
+    pub fn gen_safe_prime(bits: usize, rounds: usize, rng: &mut impl CryptoRngCore) -> Self {
+        assert!(
+            bits >= 3 && bits <= Self::BITS,
+            "safe prime bit length out of range"
+        );
+
+        let q_bits = bits - 1;
+        let top_bit = Self::ONE.wrapping_shl(q_bits as u32 - 1);
+        let max = Self::MAX.wrapping_shr((Self::BITS - q_bits) as u32);
+        let two = Self::from_u64(2);
+
+        loop {
+            let start = Self::rand(rng)
+                .rem_2k(q_bits as u32)
+                .bitor(&top_bit)
+                .bitor(&Self::ONE);
+
+            for q in Sieve::new_safe(&start, &two, &max) {
+                let p = q.wrapping_shl1().bitor(&Self::ONE);
+
+                // 先对 p 做一次以 2 为底的测试，快速排除绝大多数候选
+                if p > Self::from_u64(3) && !MillerRabin::new(&p).test_base_two() {
+                    continue;
+                }
+                if q.is_probable_prime(rounds, rng) && p.is_probable_prime(rounds, rng) {
+                    return p;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::uint::{U128, U64};
+
+    /// 1024 以内的安全素数 (OEIS A005385)
+    const SAFE_PRIMES: [u64; 26] = [
+        5, 7, 11, 23, 47, 59, 83, 107, 167, 179, 227, 263, 347, 359, 383, 467, 479, 503, 563, 587,
+        719, 839, 863, 887, 983, 1019,
+    ];
+
+    #[test]
+    fn test_is_safe_prime() {
+        let mut rng = thread_rng();
+        for n in 0..1024u64 {
+            assert_eq!(
+                SAFE_PRIMES.contains(&n),
+                U64::from_u64(n).is_safe_prime(0, &mut rng),
+                "n: {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_gen_safe_prime_small() {
+        let mut rng = thread_rng();
+        for bits in 3..=10 {
+            for _ in 0..10 {
+                let p = U64::gen_safe_prime(bits, 0, &mut rng);
+                assert_eq!(bits, p.bits());
+                assert!(SAFE_PRIMES.contains(&p.as_limbs()[0].0), "p: {}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gen_safe_prime() {
+        let mut rng = thread_rng();
+        for bits in [32, 64, 96] {
+            let p = U128::gen_safe_prime(bits, 4, &mut rng);
+            assert_eq!(bits, p.bits());
+            assert!(p.is_safe_prime(4, &mut rng));
+        }
+    }
+}
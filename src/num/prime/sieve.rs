@@ -0,0 +1,151 @@
+use crate::num::limb::Limb;
+use crate::num::prime::{SMALL_PRIMES, SMALL_PRIMES_COUNT};
+use crate::num::uint::Uint;
+
+/// 小素数筛: 依次产生 start, start + step, start + 2 * step, ... 中不被任何小素数整除的候选数
+///
+/// 维护候选数模每个小素数的余数，前进一步只需做单精度加法
+pub(crate) struct Sieve<const LIMBS: usize> {
+    candidate: Uint<LIMBS>,
+    step: Uint<LIMBS>,
+    max: Uint<LIMBS>,
+    residues: [u64; SMALL_PRIMES_COUNT],
+    step_residues: [u64; SMALL_PRIMES_COUNT],
+    started: bool,
+    /// 同时要求 2 * candidate + 1 没有小素因子
+    safe: bool,
+}
+
+impl<const LIMBS: usize> Sieve<LIMBS> {
+    /// 候选数不超过 `max`
+    pub(crate) fn new(start: &Uint<LIMBS>, step: &Uint<LIMBS>, max: &Uint<LIMBS>) -> Self {
+        let mut residues = [0u64; SMALL_PRIMES_COUNT];
+        let mut step_residues = [0u64; SMALL_PRIMES_COUNT];
+        for (i, &p) in SMALL_PRIMES.iter().enumerate() {
+            residues[i] = start.rem_limb(Limb(p)).0;
+            step_residues[i] = step.rem_limb(Limb(p)).0;
+        }
+
+        Self {
+            candidate: *start,
+            step: *step,
+            max: *max,
+            residues,
+            step_residues,
+            started: false,
+            safe: false,
+        }
+    }
+
+    /// 用于安全素数 p = 2q + 1: 候选数 q 与 2q + 1 均不被小素数整除
+    pub(crate) fn new_safe(start: &Uint<LIMBS>, step: &Uint<LIMBS>, max: &Uint<LIMBS>) -> Self {
+        Self {
+            safe: true,
+            ..Self::new(start, step, max)
+        }
+    }
+
+    /// 候选数是否有小素因子 (候选数本身为小素数时除外)
+    fn has_small_factor(&self) -> bool {
+        let largest = SMALL_PRIMES[SMALL_PRIMES_COUNT - 1];
+        if self.candidate <= Uint::from_u64(largest) {
+            return false;
+        }
+        if self.safe {
+            // 2q + 1 ≡ 2r + 1 (mod p)
+            SMALL_PRIMES
+                .iter()
+                .zip(self.residues.iter())
+                .any(|(&p, &r)| r == 0 || (2 * r + 1) % p == 0)
+        } else {
+            self.residues.contains(&0)
+        }
+    }
+
+    fn advance(&mut self) -> bool {
+        let (next, overflow) = self.candidate.overflowing_add(&self.step);
+        if overflow {
+            return false;
+        }
+        self.candidate = next;
+
+        for (i, &p) in SMALL_PRIMES.iter().enumerate() {
+            let r = self.residues[i] + self.step_residues[i];
+            self.residues[i] = if r >= p { r - p } else { r };
+        }
+        true
+    }
+}
+
+impl<const LIMBS: usize> Iterator for Sieve<LIMBS> {
+    type Item = Uint<LIMBS>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.started && !self.advance() {
+                return None;
+            }
+            self.started = true;
+
+            if self.candidate > self.max {
+                return None;
+            }
+            if !self.has_small_factor() {
+                return Some(self.candidate);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::prime::sieve::Sieve;
+    use crate::num::prime::SMALL_PRIMES;
+    use crate::num::uint::U128;
+
+    #[test]
+    fn test_sieve() {
+        let start = U128::from_u64(1_000_001);
+        let step = U128::from_u64(2);
+        let max = U128::from_u64(1_100_000);
+
+        let expect: Vec<u64> = (1_000_001..=1_100_000u64)
+            .step_by(2)
+            .filter(|n| SMALL_PRIMES.iter().all(|&p| n % p != 0))
+            .collect();
+        let actual: Vec<u64> = Sieve::new(&start, &step, &max)
+            .map(|c| c.as_limbs()[0].0)
+            .collect();
+        assert_eq!(expect, actual);
+    }
+
+    #[test]
+    fn test_safe_sieve() {
+        let start = U128::from_u64(1_000_001);
+        let step = U128::from_u64(2);
+        let max = U128::from_u64(1_100_000);
+
+        let expect: Vec<u64> = (1_000_001..=1_100_000u64)
+            .step_by(2)
+            .filter(|n| {
+                SMALL_PRIMES
+                    .iter()
+                    .all(|&p| n % p != 0 && (2 * n + 1) % p != 0)
+            })
+            .collect();
+        let actual: Vec<u64> = Sieve::new_safe(&start, &step, &max)
+            .map(|c| c.as_limbs()[0].0)
+            .collect();
+        assert_eq!(expect, actual);
+    }
+
+    #[test]
+    fn test_sieve_keeps_small_primes() {
+        let sieve = Sieve::new(&U128::from_u64(3), &U128::from_u64(2), &U128::from_u64(30));
+        let actual: Vec<u64> = sieve.map(|c| c.as_limbs()[0].0).collect();
+        assert_eq!(
+            vec![3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29],
+            actual
+        );
+    }
+}
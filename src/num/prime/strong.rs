@@ -0,0 +1,104 @@
+use rand_core::CryptoRngCore;
+
+use crate::num::monty::MontyParams;
+use crate::num::prime::sieve::Sieve;
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 使用 Gordon 算法生成 `bits` 位的强素数 p:
+    ///
+    /// - p - 1 有大素因子 r
+    /// - p + 1 有大素因子 s
+    /// - r - 1 有大素因子 t
+    pub fn gen_strong_prime(bits: usize, rounds: usize, rng: &mut impl CryptoRngCore) -> Self {
+        gordon(bits, rounds, rng).0
+    }
+}
+
+/// Gordon 算法，返回 (p, r, s, t)
+///
+/// 1. 随机生成素数 s, t
+/// 2. 取 i = 1, 2, ... 直到 r = 2it + 1 为素数
+/// 3. p_0 = 2(s^{r-2} mod r)s - 1，此时 p_0 ≡ 1 (mod r) 且 p_0 ≡ -1 (mod s)
+/// 4. 取 j 使 p = p_0 + 2jrs 为 `bits` 位的素数
+fn gordon<const LIMBS: usize>(
+    bits: usize,
+    rounds: usize,
+    rng: &mut impl CryptoRngCore,
+) -> (Uint<LIMBS>, Uint<LIMBS>, Uint<LIMBS>, Uint<LIMBS>) {
+    assert!(
+        bits >= 32 && bits <= Uint::<LIMBS>::BITS,
+        "strong prime bit length out of range"
+    );
+
+    // rs 约为 bits - 15 位，p 的取值范围内留有足够多的候选
+    let half = bits / 2;
+    let s_bits = half - 4;
+    let t_bits = half - 12;
+
+    let two = Uint::from_u64(2);
+    let min = Uint::ONE.wrapping_shl(bits as u32 - 1);
+    let max = Uint::MAX.wrapping_shr((Uint::<LIMBS>::BITS - bits) as u32);
+
+    loop {
+        let s = Uint::<LIMBS>::gen_prime(s_bits, rng);
+        let t = Uint::<LIMBS>::gen_prime(t_bits, rng);
+
+        // r = 2it + 1
+        let two_t = t.wrapping_shl1();
+        let mut r = two_t.wrapping_add(&Uint::ONE);
+        while !r.is_probable_prime(rounds, rng) {
+            r = r.wrapping_add(&two_t);
+        }
+
+        // p_0 = 2(s^{r-2} mod r)s - 1
+        let params = MontyParams::init(&r).expect("r must be odd");
+        let s_inv = params.to_monty_form(&s).pow(&(r - &two)).normalize();
+        let p0 = s_inv.wrapping_shl1() * &s - &Uint::ONE;
+
+        // p = p_0 + 2jrs，j 取使 p >= 2^{bits - 1} 的最小值
+        let step = r.wrapping_shl1() * &s;
+        if step >= min {
+            continue;
+        }
+        let j = (min - &p0 + &(step - &Uint::ONE)) / &step;
+        let start = p0 + &(j * &step);
+
+        for p in Sieve::new(&start, &step, &max) {
+            if p.is_probable_prime(rounds, rng) {
+                return (p, r, s, t);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::num::prime::strong::gordon;
+    use crate::num::uint::{U128, U256};
+
+    #[test]
+    fn test_gordon() {
+        let mut rng = thread_rng();
+        for bits in [32, 64, 100, 128] {
+            let (p, r, s, t): (U128, U128, U128, U128) = gordon(bits, 4, &mut rng);
+            assert_eq!(bits, p.bits());
+            for x in [p, r, s, t] {
+                assert!(x.is_probable_prime(4, &mut rng), "x: {}", x);
+            }
+            assert!((p - &U128::ONE) % r == U128::ZERO);
+            assert!((p + &U128::ONE) % s == U128::ZERO);
+            assert!((r - &U128::ONE) % t == U128::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_gen_strong_prime() {
+        let mut rng = thread_rng();
+        let p = U256::gen_strong_prime(256, 4, &mut rng);
+        assert_eq!(256, p.bits());
+        assert!(p.is_probable_prime(4, &mut rng));
+    }
+}
@@ -4,7 +4,7 @@ use rand_core::CryptoRngCore;
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) fn rand(rng: &mut impl CryptoRngCore) -> Self {
+    pub fn rand(rng: &mut impl CryptoRngCore) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
 
         for limb in &mut limbs {
@@ -13,4 +13,35 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         Self { limbs }
     }
+
+    /// Uniformly random integer in `[0, modulus)`, using rejection sampling.
+    #[inline(always)]
+    pub fn rand_mod(rng: &mut impl CryptoRngCore, modulus: &Self) -> Self {
+        assert!(modulus.is_nonzero(), "attempt to sample with zero modulus");
+        let bits = modulus.bits() as u32;
+
+        loop {
+            let r = Self::rand(rng).rem_2k(bits);
+            if r < *modulus {
+                return r;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::uint::U128;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_rand_mod() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let shift = rng.gen_range(0..128);
+            let m = U128::from_u128(rng.gen_range(1..=u128::MAX >> shift));
+            assert!(U128::rand_mod(&mut rng, &m) < m);
+        }
+        assert_eq!(U128::ZERO, U128::rand_mod(&mut rng, &U128::ONE));
+    }
 }
@@ -1,5 +1,6 @@
-use std::ops::Rem;
+use core::ops::Rem;
 
+use crate::num::limb::Limb;
 use crate::num::uint::Uint;
 
 impl<const LIMBS: usize> Rem<&Uint<LIMBS>> for Uint<LIMBS> {
@@ -23,18 +24,36 @@ impl<const LIMBS: usize> Rem for Uint<LIMBS> {
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
     /// self % 2^k
-    pub(crate) fn rem_2k(&self, k: u32) -> Self {
+    pub fn rem_2k(&self, k: u32) -> Self {
         let bits = self.bits() as u32;
-        if bits >= k {
+        if bits >= k && (k as usize) < Self::BITS {
             self.bitand(&(Self::ONE.wrapping_shl(k) - &Self::ONE))
         } else {
             *self
         }
     }
+
+    #[inline(always)]
+    /// self % rhs，除数为单个 limb
+    pub fn rem_limb(&self, rhs: Limb) -> Limb {
+        assert!(
+            rhs.is_nonzero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        let rhs = rhs.0 as u128;
+        let mut rem = 0u128;
+
+        for i in (0..LIMBS).rev() {
+            rem = ((rem << Limb::BITS) | self.limbs[i].0 as u128) % rhs;
+        }
+
+        Limb(rem as u64)
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::num::limb::Limb;
     use crate::num::uint::U128;
     use rand::{thread_rng, Rng};
 
@@ -51,4 +70,22 @@ mod test {
             assert_eq!(expect, actual, "a: {} k: {} m: {}", a, k, m)
         }
     }
+
+    #[test]
+    fn test_rem_limb() {
+        let mut rng = thread_rng();
+
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let b: u64 = rng.gen_range(1..=u64::MAX);
+            let expect = Limb((a % b as u128) as u64);
+            assert_eq!(
+                expect,
+                U128::from_u128(a).rem_limb(Limb(b)),
+                "a: {} b: {}",
+                a,
+                b
+            );
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use crate::num::uint::Uint;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// floor(sqrt(self))，牛顿迭代 x_{k+1} = (x_k + self / x_k) / 2
+    pub fn sqrt(&self) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+
+        // 初值 2^{ceil(bits / 2)} >= sqrt(self)，迭代单调递减直至收敛
+        let mut x = Self::ONE.wrapping_shl(self.bits().div_ceil(2) as u32);
+        loop {
+            let y = (x + &(*self / &x)).wrapping_shr1();
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_square(&self) -> bool {
+        let r = self.sqrt();
+        r * &r == *self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::num::uint::{U128, U256};
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let square = U128::from_u128(a as u128 * a as u128);
+            assert_eq!(U128::from_u64(a), square.sqrt());
+            assert!(square.is_square());
+            if a > 1 {
+                let b = square - &U128::ONE;
+                assert_eq!(U128::from_u64(a - 1), b.sqrt());
+                assert!(!b.is_square());
+            }
+        }
+        assert_eq!(U256::ONE.wrapping_shl(128) - &U256::ONE, U256::MAX.sqrt());
+        assert_eq!(U128::ZERO, U128::ZERO.sqrt());
+    }
+}
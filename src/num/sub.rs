@@ -1,4 +1,4 @@
-use std::ops::Sub;
+use core::ops::Sub;
 
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
@@ -13,26 +13,40 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         limbs: [Limb::MAX; LIMBS],
     };
     pub const BITS: usize = Limb::BITS * LIMBS;
+    pub const LIMBS: usize = LIMBS;
+    pub const BYTES: usize = Limb::BYTES * LIMBS;
 
+    /// Creates a new integer from little-endian limbs.
     #[inline(always)]
-    pub(crate) fn is_zero(&self) -> bool {
+    pub const fn new(limbs: [Limb; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    /// Borrows the little-endian limbs of this integer.
+    #[inline(always)]
+    pub const fn as_limbs(&self) -> &[Limb; LIMBS] {
+        &self.limbs
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
         self.limbs.iter().all(|&limb| limb == Limb::ZERO)
     }
 
     #[inline(always)]
-    pub(crate) fn is_nonzero(&self) -> bool {
+    pub fn is_nonzero(&self) -> bool {
         self.limbs.iter().any(|&limb| limb != Limb::ZERO)
     }
 
     #[inline(always)]
     /// 是否为奇数
-    pub(crate) fn is_odd(&self) -> bool {
+    pub fn is_odd(&self) -> bool {
         self.limbs[0].0 & 1 == 1
     }
 
     #[inline(always)]
     /// 是否为偶数
-    pub(crate) fn is_even(&self) -> bool {
+    pub fn is_even(&self) -> bool {
         !self.is_odd()
     }
 }
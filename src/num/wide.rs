@@ -1,6 +1,10 @@
-use std::cmp::Ordering;
-use std::ops::BitOr;
+use core::cmp::Ordering;
+use core::ops::BitOr;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::num::div::div_rem_limbs;
 use crate::num::limb::Limb;
 use crate::num::uint::Uint;
 
@@ -26,6 +30,18 @@ impl<const LIMBS: usize> Wide<LIMBS> {
         high: Uint::ZERO,
     };
 
+    /// Low half of the double-width value.
+    #[inline(always)]
+    pub fn low(&self) -> &Uint<LIMBS> {
+        &self.low
+    }
+
+    /// High half of the double-width value.
+    #[inline(always)]
+    pub fn high(&self) -> &Uint<LIMBS> {
+        &self.high
+    }
+
     #[inline(always)]
     pub fn sub(&self, rhs: &Self) -> Self {
         let (r, borrow) = self.sbb(rhs, Limb::ZERO);
@@ -66,9 +82,14 @@ impl<const LIMBS: usize> Wide<LIMBS> {
 
         let new_limbs: usize = LIMBS * 2;
 
-        let mut limbs = vec![Limb::ZERO; new_limbs];
-        let lhs = [self.low.limbs, self.high.limbs].concat();
+        let mut buf = [[Limb::ZERO; LIMBS]; 2];
+        let limbs = buf.as_flattened_mut();
+        let lhs = [self.low.limbs, self.high.limbs];
+        let lhs = lhs.as_flattened();
         let shift_bit = shift as usize;
+        if shift_bit >= Limb::BITS * new_limbs {
+            return Self::ZERO;
+        }
 
         let shift_num = shift_bit / Limb::BITS;
         let shr_shift = (shift_bit % Limb::BITS) as u32;
@@ -78,14 +99,13 @@ impl<const LIMBS: usize> Wide<LIMBS> {
         for i in (shift_num..new_limbs).rev() {
             let low = lhs[i].wrapping_shr(shr_shift);
             limbs[i - shift_num] = high.bitor(low);
-            high = lhs[i].wrapping_shl(shl_shift);
+            high = lhs[i].checked_shl(shl_shift).unwrap_or(Limb::ZERO);
         }
 
-        let mut ret = Self::ZERO;
-        ret.low.limbs.copy_from_slice(&limbs[0..LIMBS]);
-        ret.high.limbs.copy_from_slice(&limbs[LIMBS..new_limbs]);
-
-        ret
+        Self {
+            low: Uint::new(buf[0]),
+            high: Uint::new(buf[1]),
+        }
     }
 
     #[inline(always)]
@@ -96,9 +116,14 @@ impl<const LIMBS: usize> Wide<LIMBS> {
 
         let new_limbs: usize = LIMBS * 2;
 
-        let mut limbs = vec![Limb::ZERO; new_limbs];
-        let lhs = [self.low.limbs, self.high.limbs].concat();
+        let mut buf = [[Limb::ZERO; LIMBS]; 2];
+        let limbs = buf.as_flattened_mut();
+        let lhs = [self.low.limbs, self.high.limbs];
+        let lhs = lhs.as_flattened();
         let shift_bit = shift as usize;
+        if shift_bit >= Limb::BITS * new_limbs {
+            return Self::ZERO;
+        }
 
         let shift_num = shift_bit / Limb::BITS;
         let shl_shift = (shift_bit % Limb::BITS) as u32;
@@ -108,14 +133,13 @@ impl<const LIMBS: usize> Wide<LIMBS> {
         for i in 0..(new_limbs - shift_num) {
             let high = lhs[i].wrapping_shl(shl_shift);
             limbs[i + shift_num] = high.bitor(low);
-            low = lhs[i].wrapping_shr(shr_shift);
+            low = lhs[i].checked_shr(shr_shift).unwrap_or(Limb::ZERO);
         }
 
-        let mut ret = Self::ZERO;
-        ret.low.limbs.copy_from_slice(&limbs[0..LIMBS]);
-        ret.high.limbs.copy_from_slice(&limbs[LIMBS..new_limbs]);
-
-        ret
+        Self {
+            low: Uint::new(buf[0]),
+            high: Uint::new(buf[1]),
+        }
     }
 
     #[inline(always)]
@@ -139,57 +163,34 @@ impl<const LIMBS: usize> Wide<LIMBS> {
 
     #[inline(always)]
     pub fn rem(&self, rhs: &Uint<LIMBS>) -> Uint<LIMBS> {
-        let lz = rhs.leading_zeros();
-        let mut rem = *self;
-        let mut rhs = Self {
-            low: Uint::ZERO,
-            high: rhs.wrapping_shl(lz as u32),
-        };
-
-        let mut bd = lz + Uint::<LIMBS>::BITS;
-
-        loop {
-            let (r, borrow) = rem.sbb(&rhs, Limb::ZERO);
-            if borrow.is_zero() {
-                rem = r;
-            }
-            if bd == 0 {
-                break;
-            }
-            bd -= 1;
-            rhs = rhs.shr1();
-        }
-        rem.low
+        let (_q, r) = self.div(rhs);
+        r
     }
 
+    /// 长除法 (Knuth 算法 D)，商为双倍宽度
     #[inline(always)]
     pub fn div(&self, rhs: &Uint<LIMBS>) -> (Self, Uint<LIMBS>) {
-        let lz = rhs.leading_zeros();
-        let mut rem = *self;
-        let mut quo = Self::ZERO;
-        let mut rhs = Self {
-            low: Uint::ZERO,
-            high: rhs.wrapping_shl(lz as u32),
-        };
-
-        let mut bd = lz + Uint::<LIMBS>::BITS;
-
-        loop {
-            let (r, borrow) = rem.sbb(&rhs, Limb::ZERO);
-            if borrow.is_zero() {
-                rem = r;
-                quo = quo.bitor1();
-            }
-            if bd == 0 {
-                break;
-            }
-            bd -= 1;
-            rhs = rhs.shr1();
-            quo = quo.shl1();
-        }
-        (quo, rem.low)
+        let dividend = [self.low.limbs, self.high.limbs];
+        let mut quo = [[Limb::ZERO; LIMBS]; 2];
+        let mut rem = Uint::ZERO;
+        let mut buf = [[Limb::ZERO; LIMBS]; 4];
+        div_rem_limbs(
+            dividend.as_flattened(),
+            &rhs.limbs,
+            quo.as_flattened_mut(),
+            &mut rem.limbs,
+            buf.as_flattened_mut(),
+        );
+        (
+            Self {
+                low: Uint::new(quo[0]),
+                high: Uint::new(quo[1]),
+            },
+            rem,
+        )
     }
 
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     pub fn to_limbs(self) -> Vec<Limb> {
         let mut ret = Vec::new();
@@ -208,11 +209,15 @@ impl<const LIMBS: usize> Wide<LIMBS> {
         Self { low, high }
     }
 
-    pub(crate) fn mul(&self, rhs: &Self) -> Vec<Limb> {
+    /// 完整乘积，返回 (低 2 * LIMBS 个 limb, 高 2 * LIMBS 个 limb)
+    pub fn mul(&self, rhs: &Self) -> (Self, Self) {
         let length = LIMBS * 2;
-        let mut temp = vec![Limb::ZERO; LIMBS * 4];
-        let lhs = self.to_limbs();
-        let rhs = rhs.to_limbs();
+        let mut buf = [[Limb::ZERO; LIMBS]; 4];
+        let temp = buf.as_flattened_mut();
+        let lhs = [self.low.limbs, self.high.limbs];
+        let lhs = lhs.as_flattened();
+        let rhs = [rhs.low.limbs, rhs.high.limbs];
+        let rhs = rhs.as_flattened();
 
         for i in 0..length {
             let mut carry = Limb::ZERO;
@@ -224,7 +229,17 @@ impl<const LIMBS: usize> Wide<LIMBS> {
             temp[i + length] = carry;
         }
 
-        temp
+        let [l0, l1, h0, h1] = buf;
+        (
+            Self {
+                low: Uint::new(l0),
+                high: Uint::new(l1),
+            },
+            Self {
+                low: Uint::new(h0),
+                high: Uint::new(h1),
+            },
+        )
     }
 }
 
@@ -261,13 +276,40 @@ impl<const LIMBS: usize> PartialOrd for Wide<LIMBS> {
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     #[inline(always)]
-    pub(crate) fn to_wide<const WIDE_LIMBS: usize>(self) -> Wide<WIDE_LIMBS> {
-        assert_eq!(LIMBS / 2, WIDE_LIMBS);
+    pub fn to_wide<const WIDE_LIMBS: usize>(self) -> Wide<WIDE_LIMBS> {
+        const { assert!(WIDE_LIMBS * 2 == LIMBS) };
         let mut r = Wide::ZERO;
         r.low.limbs.copy_from_slice(&self.limbs[0..WIDE_LIMBS]);
         r.high.limbs.copy_from_slice(&self.limbs[WIDE_LIMBS..LIMBS]);
         r
     }
+
+    /// 以 self 为低半部分、hi 为高半部分拼接成 2 * LIMBS 个 limb 的整数
+    #[inline(always)]
+    pub fn concat<const WIDE_LIMBS: usize>(&self, hi: &Self) -> Uint<WIDE_LIMBS> {
+        const { assert!(LIMBS * 2 == WIDE_LIMBS) };
+        let mut r = Uint::ZERO;
+        r.limbs[0..LIMBS].copy_from_slice(&self.limbs);
+        r.limbs[LIMBS..WIDE_LIMBS].copy_from_slice(&hi.limbs);
+        r
+    }
+}
+
+impl<const LIMBS: usize> Wide<LIMBS> {
+    /// `Uint::to_wide` 的逆运算，例如两个 U1024 的乘积转为 U2048
+    ///
+    /// 目标宽度不是 2 * LIMBS 时编译失败:
+    ///
+    /// ```compile_fail
+    /// use algebra::{U2048, U4096};
+    ///
+    /// let x = U2048::ONE.to_wide::<16>();
+    /// let _: U4096 = x.to_uint();
+    /// ```
+    #[inline(always)]
+    pub fn to_uint<const WIDE_LIMBS: usize>(&self) -> Uint<WIDE_LIMBS> {
+        self.low.concat(&self.high)
+    }
 }
 
 #[cfg(test)]
@@ -381,6 +423,22 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_div() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u128 = rng.gen();
+            let b: u64 = rng.gen::<u64>() >> rng.gen_range(0..64);
+            if b == 0 {
+                continue;
+            }
+
+            let (q, r) = U128::from_u128(a).to_wide::<1>().div(&U64::from_u64(b));
+            assert_eq!(U128::from_u128(a / b as u128).to_wide(), q);
+            assert_eq!(U64::from_u64((a % b as u128) as u64), r);
+        }
+    }
+
     #[test]
     fn test_shl() {
         let mut rng = thread_rng();
@@ -395,6 +453,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_mul() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a = U128::rand(&mut rng);
+            let b = U128::rand(&mut rng);
+            let expect = a.split_mul(&b);
+
+            let (low, high) = a.to_wide::<1>().mul(&b.to_wide());
+            assert_eq!(expect.low.to_wide(), low);
+            assert_eq!(expect.high.to_wide(), high);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_to_limbs() {
         let mut rng = thread_rng();
@@ -406,4 +479,20 @@ mod test {
             assert_eq!(expect, actual);
         }
     }
+
+    #[test]
+    fn test_to_uint() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let a: u64 = rng.gen();
+            let b: u64 = rng.gen();
+            let w = U64::from_u64(a).split_mul(&U64::from_u64(b));
+            assert_eq!(U128::from_u128(a as u128 * b as u128), w.to_uint());
+
+            let c = U128::rand(&mut rng);
+            let w = c.to_wide::<1>();
+            assert_eq!(c, w.to_uint());
+            assert_eq!(c, w.low().concat(w.high()));
+        }
+    }
 }
@@ -1,4 +1,6 @@
-fn prime_factors(mut n: u64) -> Vec<u64> {
+use alloc::vec::Vec;
+
+pub fn prime_factors(mut n: u64) -> Vec<u64> {
     let mut factors = Vec::new();
     let mut i = 2;
 
@@ -1,92 +1,355 @@
-use rand::Rng;
+use core::fmt;
 
-use crate::exponent::fast_modular_exponentiation;
-use crate::gcd::gcd2;
-use crate::inverse::mod_inv;
-use crate::wilson::is_prime2;
+use rand_core::CryptoRngCore;
 
-#[derive(Debug, Default)]
-struct PrivateKey {
-    pub n: u64,
-    pub d: u64,
+use crate::num::{MontyParams, PrimeOptions, TrimmedMontyParams, Uint};
+
+/// 公钥指数 F4 = 2^16 + 1
+pub const PUBLIC_EXPONENT: u64 = 65537;
+
+/// 生成素数时 Baillie-PSW 之后追加的 Miller-Rabin 测试轮数
+const MILLER_RABIN_ROUNDS: usize = 4;
+
+/// 私钥保留 p, q 及 CRT 参数，解密和签名在模 p、模 q 下分别进行指数运算
+///
+/// 字段只读，避免修改后与预计算的蒙哥马利参数不一致；
+/// 不实现 `Copy`，避免秘密值被隐式复制；`Debug` 只输出公开部分
+#[derive(Clone)]
+pub struct PrivateKey<const LIMBS: usize> {
+    n: Uint<LIMBS>,
+    e: Uint<LIMBS>,
+    d: Uint<LIMBS>,
+    /// p > q
+    p: Uint<LIMBS>,
+    q: Uint<LIMBS>,
+    /// d mod (p - 1)
+    dp: Uint<LIMBS>,
+    /// d mod (q - 1)
+    dq: Uint<LIMBS>,
+    /// q^{-1} mod p
+    qinv: Uint<LIMBS>,
+    /// qinv 在模 p 蒙哥马利空间中的形式，Garner 重组时与差值直接做蒙哥马利乘法
+    qinv_form: Uint<LIMBS>,
+    params: MontyParams<LIMBS>,
+    params_p: TrimmedMontyParams<LIMBS>,
+    params_q: TrimmedMontyParams<LIMBS>,
 }
 
-#[derive(Debug, Default)]
-struct PublicKey {
-    pub n: u64,
-    pub e: u64,
+#[derive(Clone, Copy, Debug)]
+pub struct PublicKey<const LIMBS: usize> {
+    pub n: Uint<LIMBS>,
+    pub e: Uint<LIMBS>,
+    params: MontyParams<LIMBS>,
 }
 
-impl PrivateKey {
-    pub fn decrypt(&self, m: u64) -> u64 {
-        fast_modular_exponentiation(m, self.d, self.n)
+impl<const LIMBS: usize> PrivateKey<LIMBS> {
+    /// Builds a private key from two distinct odd primes and the public exponent.
+    /// The primes may be given in either order; the larger one is stored as `p`.
+    ///
+    /// Returns `None` if:
+    /// - `p == q`, or either of them is even or equal to one;
+    /// - `p` and `q` differ in bit length;
+    /// - `p * q` overflows `Uint<LIMBS>`;
+    /// - `e <= 1`, or `e` is not invertible modulo `(p - 1)(q - 1)`.
+    pub fn from_primes(p: &Uint<LIMBS>, q: &Uint<LIMBS>, e: &Uint<LIMBS>) -> Option<Self> {
+        let (p, q) = if p > q { (*p, *q) } else { (*q, *p) };
+        if p == q || p.is_even() || q.is_even() || q == Uint::ONE || p.bits() != q.bits() {
+            return None;
+        }
+        if *e <= Uint::ONE {
+            return None;
+        }
+
+        let (n, overflow) = p.overflowing_mul(&q);
+        if overflow {
+            return None;
+        }
+        let p_minus_one = p - &Uint::ONE;
+        let q_minus_one = q - &Uint::ONE;
+        let phi_n = p_minus_one * &q_minus_one;
+        let d = e.mod_inv(&phi_n)?;
+        let qinv = q.mod_inv(&p)?;
+        let params_p = TrimmedMontyParams::init(&p)?;
+
+        Some(Self {
+            n,
+            e: *e,
+            d,
+            p,
+            q,
+            dp: d % p_minus_one,
+            dq: d % q_minus_one,
+            qinv,
+            qinv_form: params_p.monty_form(&qinv),
+            params: MontyParams::init(&n)?,
+            params_p,
+            params_q: TrimmedMontyParams::init(&q)?,
+        })
     }
-}
 
-impl PublicKey {
-    pub fn encrypt(&self, c: u64) -> u64 {
-        fast_modular_exponentiation(c, self.e, self.n)
+    pub fn n(&self) -> &Uint<LIMBS> {
+        &self.n
     }
-}
 
-fn gen_key() -> (PrivateKey, PublicKey) {
-    let mut rng = rand::thread_rng();
+    pub fn e(&self) -> &Uint<LIMBS> {
+        &self.e
+    }
 
-    // generate p,q
-    let mut p: u64;
-    let mut q: u64;
-    loop {
-        p = rng.gen::<u32>() as u64;
-        if is_prime2(p) {
-            break;
+    pub fn d(&self) -> &Uint<LIMBS> {
+        &self.d
+    }
+
+    /// The larger of the two primes.
+    pub fn p(&self) -> &Uint<LIMBS> {
+        &self.p
+    }
+
+    pub fn q(&self) -> &Uint<LIMBS> {
+        &self.q
+    }
+
+    /// d mod (p - 1)
+    pub fn dp(&self) -> &Uint<LIMBS> {
+        &self.dp
+    }
+
+    /// d mod (q - 1)
+    pub fn dq(&self) -> &Uint<LIMBS> {
+        &self.dq
+    }
+
+    /// q^{-1} mod p
+    pub fn qinv(&self) -> &Uint<LIMBS> {
+        &self.qinv
+    }
+
+    /// Returns `None` if `c >= n` or the consistency check fails.
+    pub fn decrypt(&self, c: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        if *c >= self.n {
+            return None;
         }
+        self.private_op(c)
     }
 
-    loop {
-        q = rng.gen::<u32>() as u64;
-        if is_prime2(q) {
-            break;
+    /// Returns `None` if `m >= n` or the consistency check fails.
+    pub fn sign(&self, m: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        if *m >= self.n {
+            return None;
         }
+        self.private_op(m)
     }
 
-    let n = p * q;
-    let phi_n = (p - 1) * (q - 1);
+    /// x^d mod n，使用 CRT 与 Garner 重组:
+    ///
+    /// m_1 = x^{dp} mod p, m_2 = x^{dq} mod q
+    /// h = qinv * (m_1 - m_2) mod p
+    /// m = m_2 + h * q
+    ///
+    /// 涉及秘密值的每一步都没有依赖取值的分支: 指数运算为限定位数的蒙哥马利阶梯，
+    /// 取模为蒙哥马利约减，p > q 保证 m_2 < p 无需再约减
+    fn private_op(&self, x: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        // p, q 位数相同，x < pq 满足两侧约减的输入范围；dp < p，阶梯只需处理 p 的位数
+        let (pp, pq) = (&self.params_p, &self.params_q);
+        let m1 = pp.normalize(&pp.pow_ct(&pp.monty_form(x), &self.dp, self.p.bits()));
+        let m2 = pq.normalize(&pq.pow_ct(&pq.monty_form(x), &self.dq, self.q.bits()));
 
-    // generate e
-    let mut e;
+        let diff = m1.sub_mod(&m2, &self.p);
+        let h = pp.mul(&self.qinv_form, &diff);
+        // h < p，h * q < n 不会溢出
+        let m = m2.wrapping_add(&h.wrapping_mul(&self.q));
 
-    loop {
-        e = rng.gen::<u64>();
-        if e > 1 && e < phi_n && gcd2(e, phi_n) == 1 {
-            break;
+        // 故障攻击防护: 用公钥指数验证结果，CRT 任一分支出错都不泄露 p, q
+        let check = self.params.to_monty_form_wide(&m).pow(&self.e).normalize();
+        if check == *x {
+            Some(m)
+        } else {
+            None
         }
     }
+}
+
+impl<const LIMBS: usize> fmt::Debug for PrivateKey<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish_non_exhaustive()
+    }
+}
 
-    // calculate d
-    let d = mod_inv(e, phi_n).unwrap() as u64;
+impl<const LIMBS: usize> PublicKey<LIMBS> {
+    /// Returns `None` if `n` is even or `e <= 1`.
+    pub fn new(n: &Uint<LIMBS>, e: &Uint<LIMBS>) -> Option<Self> {
+        if *e <= Uint::ONE {
+            return None;
+        }
+        Some(Self {
+            n: *n,
+            e: *e,
+            params: MontyParams::init(n)?,
+        })
+    }
 
-    let public_key = PublicKey { e, n };
+    /// Returns `None` if `m >= n`.
+    pub fn encrypt(&self, m: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        if *m >= self.n {
+            return None;
+        }
+        Some(self.params.to_monty_form(m).pow(&self.e).normalize())
+    }
 
-    let private_key = PrivateKey { n, d };
+    pub fn verify(&self, m: &Uint<LIMBS>, s: &Uint<LIMBS>) -> bool {
+        self.encrypt(s) == Some(*m)
+    }
+}
 
-    (private_key, public_key)
+/// 生成 `Uint::<LIMBS>::BITS / 2` 位且 gcd(p - 1, e) = 1 的素数，
+/// 最高两位置 1 以保证 p * q 恰好为 `BITS` 位
+fn gen_prime<const LIMBS: usize>(e: &Uint<LIMBS>, rng: &mut impl CryptoRngCore) -> Uint<LIMBS> {
+    let options = PrimeOptions {
+        top_two_bits: true,
+        coprime_to: Some(*e),
+        rounds: MILLER_RABIN_ROUNDS,
+        ..PrimeOptions::new(Uint::<LIMBS>::BITS / 2)
+    };
+    Uint::gen_prime_with(&options, rng)
+}
+
+pub fn gen_key<const LIMBS: usize>(
+    rng: &mut impl CryptoRngCore,
+) -> (PrivateKey<LIMBS>, PublicKey<LIMBS>) {
+    let e = Uint::from_u64(PUBLIC_EXPONENT);
+
+    loop {
+        // generate p,q
+        let p = gen_prime(&e, rng);
+        let q = gen_prime(&e, rng);
+
+        if let Some(private_key) = PrivateKey::from_primes(&p, &q, &e) {
+            let public_key = PublicKey::new(&private_key.n, &e).unwrap();
+            return (private_key, public_key);
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::num::{U256, U512};
 
     #[test]
     fn test_my_rsa() {
         let mut rng = rand::thread_rng();
-        let (private, public) = gen_key();
-        println!("private key: {:?}", private);
-        println!("public key: {:?}", public);
-        for _i in 0..1000 {
-            let m = rng.gen::<u32>() as u64;
-            let c = public.encrypt(m);
-            let dm = private.decrypt(c);
+        let (private, public) = gen_key::<4>(&mut rng);
+        assert_eq!(U256::BITS, public.n.bits());
+        for _i in 0..100 {
+            let m = U256::rand_mod(&mut rng, &public.n);
+            let c = public.encrypt(&m).unwrap();
+            let dm = private.decrypt(&c).unwrap();
             assert_eq!(m, dm);
         }
     }
+
+    #[test]
+    fn test_crt_matches_full_exponentiation() {
+        let mut rng = rand::thread_rng();
+        let (private, public) = gen_key::<4>(&mut rng);
+        let params = MontyParams::init(private.n()).unwrap();
+        for _ in 0..20 {
+            let c = U256::rand_mod(&mut rng, &public.n);
+            let expect = params.to_monty_form(&c).pow(private.d()).normalize();
+            assert_eq!(Some(expect), private.decrypt(&c));
+        }
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let mut rng = rand::thread_rng();
+        let (private, public) = gen_key::<4>(&mut rng);
+        let m = U256::rand_mod(&mut rng, &public.n);
+        let s = private.sign(&m).unwrap();
+        assert!(public.verify(&m, &s));
+        assert!(!public.verify(&(m + &U256::ONE), &s));
+    }
+
+    #[test]
+    fn test_fault_detection() {
+        let mut rng = rand::thread_rng();
+        let (mut private, public) = gen_key::<4>(&mut rng);
+        let m = U256::rand_mod(&mut rng, &public.n);
+        let c = public.encrypt(&m).unwrap();
+
+        // 模拟 CRT 分支中的故障
+        private.dp = private.dp.wrapping_add(&U256::ONE);
+        assert_eq!(None, private.decrypt(&c));
+        assert_eq!(None, private.sign(&m));
+    }
+
+    #[test]
+    fn test_from_primes_rejects() {
+        let e = U256::from_u64(PUBLIC_EXPONENT);
+        let p = U256::from_u64(61);
+        let q = U256::from_u64(53);
+        assert!(PrivateKey::from_primes(&p, &q, &e).is_some());
+        assert!(PrivateKey::from_primes(&p, &p, &e).is_none());
+        assert!(PrivateKey::from_primes(&U256::from_u64(62), &q, &e).is_none());
+        assert!(PrivateKey::from_primes(&U256::ONE, &q, &e).is_none());
+        assert!(PrivateKey::from_primes(&p, &q, &U256::ONE).is_none());
+        assert!(PrivateKey::from_primes(&p, &q, &U256::ZERO).is_none());
+
+        // 位数不同
+        assert!(PrivateKey::from_primes(&p, &U256::from_u64(7), &e).is_none());
+
+        // 乘积超出 256 位
+        let big = U256::MAX.wrapping_shr(1);
+        let big2 = big - &U256::from_u64(2);
+        assert!(PrivateKey::from_primes(&big, &big2, &e).is_none());
+
+        // 较大的素数存为 p
+        let private = PrivateKey::from_primes(&q, &p, &e).unwrap();
+        assert_eq!(&p, private.p());
+        assert_eq!(&q, private.q());
+        assert_eq!(U256::ONE, private.q().mul_mod(private.qinv(), private.p()));
+    }
+
+    #[test]
+    fn test_debug_hides_secrets() {
+        let mut rng = rand::thread_rng();
+        let (private, _) = gen_key::<4>(&mut rng);
+        let s = format!("{:?}", private);
+        assert!(s.contains(&format!("{:?}", private.n())));
+        assert!(!s.contains(&format!("{:?}", private.p())));
+        assert!(!s.contains(&format!("{:?}", private.d())));
+    }
+
+    #[test]
+    fn test_private_op_rejects_out_of_range() {
+        let mut rng = rand::thread_rng();
+        let (private, _) = gen_key::<4>(&mut rng);
+        assert_eq!(None, private.decrypt(private.n()));
+        assert_eq!(None, private.sign(&U256::MAX));
+        assert_eq!(Some(U256::ZERO), private.decrypt(&U256::ZERO));
+    }
+
+    #[test]
+    fn test_public_key_rejects() {
+        let n = U256::from_u64(61 * 53);
+        assert!(PublicKey::new(&n, &U256::ONE).is_none());
+        assert!(PublicKey::new(&n, &U256::ZERO).is_none());
+        assert!(PublicKey::new(&U256::from_u64(62), &U256::from_u64(3)).is_none());
+
+        let public = PublicKey::new(&n, &U256::from_u64(17)).unwrap();
+        assert_eq!(None, public.encrypt(&n));
+        assert_eq!(None, public.encrypt(&U256::MAX));
+        assert!(!public.verify(&U256::ONE, &n));
+    }
+
+    #[test]
+    fn test_rsa_512() {
+        let mut rng = rand::thread_rng();
+        let (private, public) = gen_key::<8>(&mut rng);
+        assert_eq!(U512::BITS, public.n.bits());
+        let m = U512::rand_mod(&mut rng, &public.n);
+        assert_eq!(Some(m), private.decrypt(&public.encrypt(&m).unwrap()));
+    }
 }
@@ -1,4 +1,4 @@
-fn is_prime(n: u64) -> bool {
+pub fn is_prime(n: u64) -> bool {
     if n < 2 {
         return false;
     }
@@ -27,7 +27,7 @@ pub fn is_prime2(n: u64) -> bool {
     }
 
     let mut i = 5;
-    let sqrt_n = (n as f64).sqrt() as u64;
+    let sqrt_n = n.isqrt();
     while i <= sqrt_n {
         if n % i == 0 || n % (i + 2) == 0 {
             return false;